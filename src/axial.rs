@@ -53,6 +53,14 @@ impl Axial {
         ]
     }
 
+    pub fn shared_neighbours(&self, other: &Axial) -> Vec<Axial> {
+        let other_neighbours = other.surrounding_positions();
+        self.surrounding_positions()
+            .into_iter()
+            .filter(|position| other_neighbours.contains(position))
+            .collect()
+    }
+
     pub fn north(&self) -> Axial {
         Axial::new(self.q, self.r - 1)
     }
@@ -89,3 +97,22 @@ mod surrounding_tiles {
         assert_eq!(tiles.len(), 6);
     }
 }
+
+#[cfg(test)]
+mod shared_neighbours {
+    use super::*;
+
+    #[test]
+    fn adjacent() {
+        let shared = Axial::zero().shared_neighbours(&Axial::zero().north());
+        assert_eq!(2, shared.len());
+        assert_eq!(true, shared.contains(&Axial::zero().north_west()));
+        assert_eq!(true, shared.contains(&Axial::zero().north_east()));
+    }
+
+    #[test]
+    fn not_adjacent() {
+        let shared = Axial::zero().shared_neighbours(&Axial::new(3, 0));
+        assert_eq!(0, shared.len());
+    }
+}
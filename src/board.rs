@@ -1,8 +1,12 @@
 use axial::Axial;
 use bounds::Bounds;
+use move_error::MoveError;
+use moves::Move;
+use piece_id::PieceId;
 use placed_tile::PlacedTile;
 use player::PlayerNumber;
 use render::RenderStdout;
+use rule_set::RuleSet;
 use std::cmp;
 use std::cmp::Ordering;
 use std::collections::HashMap;
@@ -12,46 +16,585 @@ use std::iter::repeat;
 use std::marker::PhantomData;
 use std::rc::Rc;
 use tile::Tile;
+use tile::TileKind;
 
 pub struct Board {
-    tiles: HashMap<Axial, Rc<PlacedTile>>,
+    tiles: HashMap<Axial, Vec<Rc<PlacedTile>>>,
     render: RenderStdout,
+    turn: usize,
+    history: Vec<Move>,
+    undone: Vec<Move>,
+    // Moved tiles as they were before each move in the history
+    moved_tiles: Vec<Rc<PlacedTile>>,
+    rules: RuleSet,
+    // The position after each move in the history
+    positions: Vec<PositionKey>,
+}
+
+// Identifies a position regardless of where the hive sits on the plane, the
+// stacks are listed from the bottom up and shifted so the smallest q and r
+// are both zero
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct PositionKey {
+    stacks: Vec<(Axial, Vec<(TileKind, PlayerNumber)>)>,
+    to_move: PlayerNumber,
 }
 
 impl Board {
     pub fn new() -> Board {
+        Board::with_rules(RuleSet::default())
+    }
+
+    pub fn with_rules(rules: RuleSet) -> Board {
         Board {
             tiles: HashMap::new(),
             render: RenderStdout::new(),
+            turn: 0,
+            history: Vec::new(),
+            undone: Vec::new(),
+            moved_tiles: Vec::new(),
+            rules,
+            positions: Vec::new(),
         }
     }
 
-    pub fn place_tile(&mut self, tile: Rc<Tile>, position: Axial, player: PlayerNumber) {
-        assert!(
-            self.get_possible_tile_placements(player)
-                .contains(&position),
-            "cannot place tile at position"
-        );
+    pub fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+
+    pub fn place_tile(
+        &mut self,
+        tile: Rc<Tile>,
+        position: Axial,
+        player: PlayerNumber,
+    ) -> Result<(), MoveError> {
+        if player != self.current_player() {
+            return Err(MoveError::NotYourTurn);
+        }
+        if tile.kind() != TileKind::Queen && self.must_place_queen(player) {
+            return Err(MoveError::QueenMustBePlaced);
+        }
+        if tile.kind() == TileKind::Queen && self.may_not_place_queen(player) {
+            return Err(MoveError::TournamentOpening);
+        }
+        if !self
+            .get_possible_tile_placements(player)
+            .contains(&position)
+        {
+            return Err(if self.is_occupied(&position) {
+                MoveError::OccupiedHex
+            } else if self.tiles.len() > 1 && self.touches_opponent(&position, player) {
+                MoveError::TouchesOpponent
+            } else {
+                MoveError::NotTouchingHive
+            });
+        }
+
+        self.add_tile(tile.clone(), position, player);
+        self.record(Move::Place(tile, position));
+        Ok(())
+    }
+
+    pub fn move_tile(&mut self, from: Axial, to: Axial) -> Result<(), MoveError> {
+        let player = match self.top_tile(&from) {
+            None => return Err(MoveError::NoTileToMove),
+            Some(placed_tile) => placed_tile.id.player,
+        };
+        if player != self.current_player() {
+            return Err(MoveError::NotYourTurn);
+        }
+        if !self.has_placed_queen(player) {
+            return Err(MoveError::QueenNotPlaced);
+        }
+        if self.is_pinned(&from) {
+            return Err(MoveError::BreaksHive);
+        }
+        if !self.possible_moves(from).contains(&to) {
+            return Err(MoveError::IllegalMove);
+        }
+
+        self.relocate_tile(from, to, false);
+        self.record(Move::Move(from, to));
+        Ok(())
+    }
+
+    pub fn throw_tile(&mut self, from: Axial, to: Axial) -> Result<(), MoveError> {
+        if !self.is_occupied(&from) {
+            return Err(MoveError::NoTileToMove);
+        }
+        let player = self.current_player();
+        if !self.has_placed_queen(player) {
+            return Err(MoveError::QueenNotPlaced);
+        }
+        if self.is_pinned(&from) {
+            return Err(MoveError::BreaksHive);
+        }
+        if !self.can_be_thrown(from, to) {
+            return Err(MoveError::IllegalMove);
+        }
+
+        self.relocate_tile(from, to, true);
+        self.record(Move::Throw(from, to));
+        Ok(())
+    }
 
-        let placed_tile = Rc::new(PlacedTile::new(tile, position, player));
-        self.tiles.insert(position, placed_tile.clone());
+    // Whether one of the current player's tiles can throw the tile at `from`
+    pub fn can_be_thrown(&self, from: Axial, to: Axial) -> bool {
+        let player = self.current_player();
+        from.surrounding_positions().iter().any(|position| {
+            self.top_tile(position)
+                .is_some_and(|placed_tile| placed_tile.id.player == player)
+                && self.possible_throws(*position).contains(&(from, to))
+        })
+    }
+
+    pub fn pass(&mut self) {
+        self.record(Move::Pass);
+    }
+
+    // Plays `m` for the current player without checking their reserve
+    pub fn play(&mut self, m: Move) -> Result<(), MoveError> {
+        match m {
+            Move::Place(tile, position) => {
+                let player = self.current_player();
+                self.place_tile(tile, position, player)
+            }
+            Move::Move(from, to) => self.move_tile(from, to),
+            Move::Throw(from, to) => self.throw_tile(from, to),
+            Move::Pass => {
+                self.pass();
+                Ok(())
+            }
+        }
+    }
+
+    // Playing the move that would be redone keeps the rest of the redo history
+    fn record(&mut self, m: Move) {
+        if self.undone.last() == Some(&m) {
+            self.undone.pop();
+        } else {
+            self.undone.clear();
+        }
+        self.history.push(m);
+        self.turn += 1;
+        let position = self.position_key();
+        self.positions.push(position);
+    }
+
+    pub fn history(&self) -> &[Move] {
+        &self.history
+    }
+
+    pub fn undo(&mut self) -> Option<Move> {
+        let m = self.history.pop()?;
+        match m {
+            Move::Place(_, position) => {
+                self.pop_tile(position);
+            }
+            Move::Move(_, to) | Move::Throw(_, to) => {
+                self.pop_tile(to);
+                let moved_tile = self.moved_tiles.pop().unwrap();
+                self.push_tile(moved_tile);
+            }
+            Move::Pass => {}
+        }
+        self.turn -= 1;
+        self.positions.pop();
+        self.undone.push(m.clone());
+        Some(m)
+    }
+
+    pub fn redo(&mut self) -> Option<Move> {
+        let m = self.undone.pop()?;
+        match m {
+            Move::Place(ref tile, position) => {
+                let player = self.current_player();
+                self.add_tile(tile.clone(), position, player);
+            }
+            Move::Move(from, to) => self.relocate_tile(from, to, false),
+            Move::Throw(from, to) => self.relocate_tile(from, to, true),
+            Move::Pass => {}
+        }
+        self.turn += 1;
+        self.history.push(m.clone());
+        let position = self.position_key();
+        self.positions.push(position);
+        Some(m)
+    }
+
+    pub fn position_key(&self) -> PositionKey {
+        let min_q = self
+            .tiles
+            .keys()
+            .map(|position| position.q)
+            .min()
+            .unwrap_or(0);
+        let min_r = self
+            .tiles
+            .keys()
+            .map(|position| position.r)
+            .min()
+            .unwrap_or(0);
+        let mut stacks: Vec<_> = self
+            .tiles
+            .iter()
+            .map(|(position, stack)| {
+                (
+                    Axial::new(position.q - min_q, position.r - min_r),
+                    stack
+                        .iter()
+                        .map(|placed_tile| (placed_tile.tile.kind(), placed_tile.id.player))
+                        .collect(),
+                )
+            })
+            .collect();
+        stacks.sort_by_key(|(position, _)| *position);
+        PositionKey {
+            stacks,
+            to_move: self.current_player(),
+        }
+    }
+
+    // How many times the current position has come up during the game
+    pub fn repetitions(&self) -> usize {
+        match self.positions.last() {
+            Some(current) => self
+                .positions
+                .iter()
+                .filter(|position| *position == current)
+                .count(),
+            None => 0,
+        }
+    }
+
+    fn add_tile(&mut self, tile: Rc<Tile>, position: Axial, player: PlayerNumber) {
+        let turn = self.turn;
+        let id = self.next_piece_id(player, tile.kind());
+        self.push_tile(Rc::new(PlacedTile {
+            last_moved: Some(turn),
+            ..PlacedTile::new(tile, position, id)
+        }));
+    }
+
+    // Tiles leave the reserve in order so the next piece of a kind is
+    // numbered after those already on the board
+    pub fn next_piece_id(&self, player: PlayerNumber, kind: TileKind) -> PieceId {
+        let placed = self
+            .tiles
+            .values()
+            .flat_map(|stack| stack.iter())
+            .filter(|placed_tile| placed_tile.id.player == player && placed_tile.id.kind == kind)
+            .count();
+        PieceId::new(player, kind, placed + 1)
+    }
+
+    fn relocate_tile(&mut self, from: Axial, to: Axial, thrown: bool) {
+        let moved_tile = self.pop_tile(from).unwrap();
+        let turn = self.turn;
+        self.push_tile(Rc::new(PlacedTile {
+            last_moved: Some(turn),
+            thrown,
+            ..PlacedTile::new(moved_tile.tile.clone(), to, moved_tile.id)
+        }));
+        self.moved_tiles.push(moved_tile);
+    }
+
+    fn moved_last_turn(&self, position: &Axial) -> bool {
+        self.top_tile(position).is_some_and(|placed_tile| {
+            placed_tile.last_moved.map(|turn| turn + 1) == Some(self.turn)
+        })
+    }
+
+    // A thrown tile can't move or be thrown again on the following turn, for
+    // an opponent's tile that's its owner's next turn
+    fn is_frozen(&self, position: &Axial) -> bool {
+        self.moved_last_turn(position) && self.top_tile(position).unwrap().thrown
+    }
+
+    pub fn turn(&self) -> usize {
+        self.turn
+    }
+
+    pub fn current_player(&self) -> PlayerNumber {
+        if self.turn.is_multiple_of(2) {
+            PlayerNumber::One
+        } else {
+            PlayerNumber::Two
+        }
+    }
+
+    pub fn turns_taken(&self, player: PlayerNumber) -> usize {
+        match player {
+            PlayerNumber::One => self.turn.div_ceil(2),
+            PlayerNumber::Two => self.turn / 2,
+        }
+    }
+
+    pub fn has_placed_queen(&self, player: PlayerNumber) -> bool {
+        self.queen_position(player).is_some()
+    }
+
+    pub fn queen_position(&self, player: PlayerNumber) -> Option<Axial> {
+        self.tiles
+            .iter()
+            .find(|(_, stack)| {
+                stack.iter().any(|placed_tile| {
+                    placed_tile.id.player == player && placed_tile.tile.kind() == TileKind::Queen
+                })
+            })
+            .map(|(position, _)| *position)
+    }
+
+    pub fn is_queen_surrounded(&self, player: PlayerNumber) -> bool {
+        match self.queen_position(player) {
+            None => false,
+            Some(position) => position
+                .surrounding_positions()
+                .iter()
+                .all(|surrounding_position| self.is_occupied(surrounding_position)),
+        }
+    }
+
+    fn must_place_queen(&self, player: PlayerNumber) -> bool {
+        !self.has_placed_queen(player) && self.turns_taken(player) + 1 >= self.rules.queen_deadline
+    }
+
+    fn may_not_place_queen(&self, player: PlayerNumber) -> bool {
+        self.rules.tournament_opening && self.turns_taken(player) == 0
+    }
+
+    // The render only knows about the tile on top of each stack
+    fn push_tile(&mut self, placed_tile: Rc<PlacedTile>) {
+        if let Some(covered_tile) = self.top_tile(&placed_tile.position).cloned() {
+            self.render.remove(&covered_tile);
+        }
         self.render.push(placed_tile.clone());
+        self.tiles
+            .entry(placed_tile.position)
+            .or_default()
+            .push(placed_tile);
+    }
+
+    fn pop_tile(&mut self, position: Axial) -> Option<Rc<PlacedTile>> {
+        let placed_tile = self.lift_tile(position);
+        if let Some(ref placed_tile) = placed_tile {
+            self.render.remove(placed_tile);
+            if let Some(uncovered_tile) = self.top_tile(&position).cloned() {
+                self.render.push(uncovered_tile);
+            }
+        }
+        placed_tile
+    }
+
+    fn lift_tile(&mut self, position: Axial) -> Option<Rc<PlacedTile>> {
+        let (placed_tile, now_empty) = match self.tiles.get_mut(&position) {
+            None => return None,
+            Some(stack) => (stack.pop(), stack.is_empty()),
+        };
+        if now_empty {
+            self.tiles.remove(&position);
+        }
+        placed_tile
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    pub fn is_occupied(&self, position: &Axial) -> bool {
+        self.tiles.contains_key(position)
+    }
+
+    pub fn height(&self, position: &Axial) -> usize {
+        self.tiles.get(position).map_or(0, |stack| stack.len())
+    }
+
+    pub fn piece_position(&self, id: PieceId) -> Option<Axial> {
+        self.tiles
+            .iter()
+            .find(|(_, stack)| stack.iter().any(|placed_tile| placed_tile.id == id))
+            .map(|(position, _)| *position)
+    }
+
+    pub fn top_tile(&self, position: &Axial) -> Option<&Rc<PlacedTile>> {
+        self.tiles.get(position).and_then(|stack| stack.last())
+    }
+
+    pub fn possible_moves(&self, position: Axial) -> Vec<Axial> {
+        if self.is_pinned(&position) || self.is_frozen(&position) {
+            return Vec::new();
+        }
+
+        match self.top_tile(&position) {
+            None => Vec::new(),
+            Some(placed_tile) => {
+                let lifted = self.without_tile_at(position);
+                placed_tile
+                    .tile
+                    .possible_moves(&lifted, position)
+                    .into_iter()
+                    .filter(|to| lifted.touches_hive(to))
+                    .collect()
+            }
+        }
+    }
+
+    // A pillbug can't throw a tile the opponent has just moved
+    pub fn possible_throws(&self, position: Axial) -> Vec<(Axial, Axial)> {
+        if self.is_frozen(&position) {
+            return Vec::new();
+        }
+
+        match self.top_tile(&position) {
+            None => Vec::new(),
+            Some(placed_tile) => placed_tile
+                .tile
+                .possible_throws(self, position)
+                .into_iter()
+                .filter(|(from, _)| !self.is_pinned(from) && !self.moved_last_turn(from))
+                .collect(),
+        }
+    }
+
+    // A slide must keep the tile touching the hive, so exactly one of the two
+    // positions either side of the edge being crossed can be occupied
+    pub fn can_slide(&self, from: Axial, to: Axial) -> bool {
+        if self.is_occupied(&to) || !from.surrounding_positions().contains(&to) {
+            return false;
+        }
+
+        let shared_neighbours = from.shared_neighbours(&to);
+        let occupied = shared_neighbours
+            .iter()
+            .filter(|position| self.is_occupied(position))
+            .count();
+        occupied == 1
+    }
+
+    // Tiles that can't be lifted without splitting the hive in two
+    pub fn pinned_positions(&self) -> Vec<Axial> {
+        let mut pinned = self
+            .tiles
+            .keys()
+            .filter(|position| self.is_pinned(position))
+            .cloned()
+            .collect::<Vec<_>>();
+        pinned.sort();
+        pinned
+    }
+
+    pub fn is_pinned(&self, position: &Axial) -> bool {
+        self.height(position) == 1 && !self.without_tile_at(*position).is_connected()
+    }
+
+    fn is_connected(&self) -> bool {
+        let start = match self.tiles.keys().next() {
+            None => return true,
+            Some(position) => *position,
+        };
+
+        let mut visited = vec![start];
+        let mut to_visit = vec![start];
+        while let Some(position) = to_visit.pop() {
+            for surrounding_position in position.surrounding_positions() {
+                if self.is_occupied(&surrounding_position)
+                    && !visited.contains(&surrounding_position)
+                {
+                    visited.push(surrounding_position);
+                    to_visit.push(surrounding_position);
+                }
+            }
+        }
+        visited.len() == self.tiles.len()
+    }
+
+    fn touches_hive(&self, position: &Axial) -> bool {
+        self.is_occupied(position)
+            || position
+                .surrounding_positions()
+                .iter()
+                .any(|surrounding_position| self.is_occupied(surrounding_position))
+    }
+
+    pub fn without_tile_at(&self, position: Axial) -> Board {
+        let mut b = Board {
+            tiles: self.tiles.clone(),
+            render: RenderStdout::new(),
+            turn: self.turn,
+            history: Vec::new(),
+            undone: Vec::new(),
+            moved_tiles: Vec::new(),
+            rules: self.rules,
+            positions: Vec::new(),
+        };
+        b.lift_tile(position);
+        b
     }
 
     pub fn render(&self) {
         self.render.render();
     }
 
+    // Every move `player` could make with `hand` still to be placed, passing
+    // is only legal when there is nothing else to do. Nothing can move until
+    // the queen is placed and she must be placed by the rules' deadline
+    pub fn legal_moves(&self, player: PlayerNumber, hand: &[Rc<Tile>]) -> Vec<Move> {
+        let mut moves = Vec::new();
+        let placements = self.get_possible_tile_placements(player);
+        for (index, tile) in hand.iter().enumerate() {
+            if hand[..index]
+                .iter()
+                .any(|other| other.kind() == tile.kind())
+                || (tile.kind() != TileKind::Queen && self.must_place_queen(player))
+                || (tile.kind() == TileKind::Queen && self.may_not_place_queen(player))
+            {
+                continue;
+            }
+            for position in &placements {
+                moves.push(Move::Place(tile.clone(), *position));
+            }
+        }
+
+        let mut positions = self
+            .tiles
+            .iter()
+            .filter(|(_, stack)| stack.last().unwrap().id.player == player)
+            .map(|(position, _)| *position)
+            .collect::<Vec<_>>();
+        positions.sort();
+        if self.has_placed_queen(player) {
+            for from in positions {
+                for to in self.possible_moves(from) {
+                    moves.push(Move::Move(from, to));
+                }
+                for (thrown_from, to) in self.possible_throws(from) {
+                    let m = Move::Throw(thrown_from, to);
+                    if !moves.contains(&m) {
+                        moves.push(m);
+                    }
+                }
+            }
+        }
+
+        if moves.is_empty() {
+            moves.push(Move::Pass);
+        }
+        moves
+    }
+
+    fn touches_opponent(&self, position: &Axial, player: PlayerNumber) -> bool {
+        position
+            .surrounding_positions()
+            .iter()
+            .filter_map(|surrounding_position| self.top_tile(surrounding_position))
+            .any(|placed_tile| placed_tile.id.player != player)
+    }
+
     pub fn get_possible_tile_placements(&self, player: PlayerNumber) -> Vec<Axial> {
         let position_is_free = |position: &Axial| !self.tiles.contains_key(position);
 
-        let adjacent_tiles_all_same_player = |position: &Axial| {
-            position
-                .surrounding_positions()
-                .iter()
-                .filter_map(|surrounding_position| self.tiles.get(surrounding_position))
-                .all(|placed_tile| placed_tile.player == player)
-        };
+        let adjacent_tiles_all_same_player =
+            |position: &Axial| !self.touches_opponent(position, player);
 
         match self.tiles.len() {
             0 => vec![Axial::zero()],
@@ -73,16 +616,36 @@ impl Board {
 }
 
 #[cfg(test)]
-mod helpers {
+pub mod helpers {
     use super::*;
     use axial::Axial;
     use tile::ant;
 
+    // Ignores placement rules so tests can set up any position, tiles placed
+    // at the same position are stacked in order
+    pub fn board_with_players(tiles: Vec<(Rc<Tile>, Axial, PlayerNumber)>) -> Board {
+        let mut b = Board::new();
+        for (tile, position, player) in tiles {
+            let id = b.next_piece_id(player, tile.kind());
+            b.push_tile(Rc::new(PlacedTile::new(tile, position, id)));
+        }
+        b
+    }
+
+    pub fn board_with(tiles: Vec<(Rc<Tile>, Axial)>) -> Board {
+        board_with_players(
+            tiles
+                .into_iter()
+                .map(|(tile, position)| (tile, position, PlayerNumber::One))
+                .collect(),
+        )
+    }
+
     pub fn board_with_tiles_at(positions: Vec<Axial>) -> Board {
         let mut b = Board::new();
         let mut current_player = PlayerNumber::One;
         for pos in positions {
-            b.place_tile(ant(), pos, current_player);
+            b.place_tile(ant(), pos, current_player).unwrap();
             current_player = current_player.other();
         }
         b
@@ -167,3 +730,666 @@ mod get_possible_tile_placements {
         );
     }
 }
+
+#[cfg(test)]
+mod stacks {
+    use super::helpers::*;
+    use super::*;
+    use tile::{ant, beetle, queen};
+
+    #[test]
+    fn only_top_tile_counts_for_placement() {
+        let b = board_with_players(vec![
+            (ant(), Axial::zero(), PlayerNumber::Two),
+            (ant(), Axial::zero().south(), PlayerNumber::One),
+            (beetle(), Axial::zero(), PlayerNumber::One),
+        ]);
+
+        assert_eq!(8, b.get_possible_tile_placements(PlayerNumber::One).len());
+        assert_eq!(0, b.get_possible_tile_placements(PlayerNumber::Two).len());
+    }
+
+    #[test]
+    fn move_onto_and_off_stack() {
+        let mut b = board_with(vec![
+            (ant(), Axial::zero()),
+            (beetle(), Axial::zero().south()),
+            (queen(), Axial::zero().south_east()),
+        ]);
+
+        b.move_tile(Axial::zero().south(), Axial::zero()).unwrap();
+        b.pass();
+        assert_eq!(2, b.height(&Axial::zero()));
+        assert_eq!(false, b.is_occupied(&Axial::zero().south()));
+        assert_eq!('B', b.top_tile(&Axial::zero()).unwrap().tile.render());
+
+        b.move_tile(Axial::zero(), Axial::zero().north()).unwrap();
+        assert_eq!(1, b.height(&Axial::zero()));
+        assert_eq!('A', b.top_tile(&Axial::zero()).unwrap().tile.render());
+    }
+
+    #[test]
+    fn illegal_move() {
+        let mut b = board_with(vec![
+            (ant(), Axial::zero()),
+            (beetle(), Axial::zero().south()),
+            (queen(), Axial::zero().south_east()),
+        ]);
+        assert_eq!(
+            Err(MoveError::IllegalMove),
+            b.move_tile(Axial::zero().south(), Axial::zero().south().south())
+        );
+    }
+}
+
+#[cfg(test)]
+mod pinned_positions {
+    use super::helpers::*;
+    use super::*;
+    use tile::{ant, beetle, queen};
+
+    #[test]
+    fn single_tile() {
+        let b = board_with(vec![(queen(), Axial::zero())]);
+        assert_eq!(0, b.pinned_positions().len());
+    }
+
+    #[test]
+    fn line_of_tiles() {
+        let b = board_with(vec![
+            (ant(), Axial::zero()),
+            (queen(), Axial::zero().south()),
+            (ant(), Axial::zero().south().south()),
+        ]);
+        assert_eq!(vec![Axial::zero().south()], b.pinned_positions());
+        assert_eq!(0, b.possible_moves(Axial::zero().south()).len());
+    }
+
+    #[test]
+    fn ring_of_tiles() {
+        let b = board_with(
+            Axial::zero()
+                .surrounding_positions()
+                .into_iter()
+                .map(|position| (ant(), position))
+                .collect(),
+        );
+        assert_eq!(0, b.pinned_positions().len());
+    }
+
+    #[test]
+    fn stacked_tile_is_never_pinned() {
+        let b = board_with(vec![
+            (ant(), Axial::zero()),
+            (queen(), Axial::zero().south()),
+            (beetle(), Axial::zero().south()),
+            (ant(), Axial::zero().south().south()),
+        ]);
+        assert_eq!(0, b.pinned_positions().len());
+        assert_eq!(6, b.possible_moves(Axial::zero().south()).len());
+    }
+}
+
+#[cfg(test)]
+mod can_slide {
+    use super::helpers::*;
+    use super::*;
+    use tile::ant;
+
+    #[test]
+    fn along_single_tile() {
+        let b = board_with_tiles_at(vec![Axial::zero(), Axial::zero().south()]);
+        assert_eq!(
+            true,
+            b.can_slide(Axial::zero().south(), Axial::zero().south_east())
+        );
+    }
+
+    #[test]
+    fn away_from_hive() {
+        let b = board_with_tiles_at(vec![Axial::zero(), Axial::zero().south()]);
+        assert_eq!(
+            false,
+            b.can_slide(Axial::zero().south(), Axial::zero().south().south())
+        );
+    }
+
+    #[test]
+    fn onto_occupied_position() {
+        let b = board_with_tiles_at(vec![Axial::zero(), Axial::zero().south()]);
+        assert_eq!(false, b.can_slide(Axial::zero().south(), Axial::zero()));
+    }
+
+    #[test]
+    fn not_adjacent() {
+        let b = board_with_tiles_at(vec![Axial::zero(), Axial::zero().south()]);
+        assert_eq!(
+            false,
+            b.can_slide(Axial::zero().south(), Axial::zero().north_east())
+        );
+    }
+
+    #[test]
+    fn through_gate() {
+        let b = board_with(vec![
+            (ant(), Axial::zero().north()),
+            (ant(), Axial::zero().north_east()),
+            (ant(), Axial::zero().south_east()),
+        ]);
+        assert_eq!(
+            false,
+            b.can_slide(Axial::zero().north_east(), Axial::zero())
+        );
+    }
+}
+
+#[cfg(test)]
+mod legal_moves {
+    use super::helpers::*;
+    use super::*;
+    use tile::{ant, queen, standard_game_tiles};
+
+    #[test]
+    fn empty_board() {
+        let b = Board::new();
+        let moves = b.legal_moves(PlayerNumber::One, &standard_game_tiles());
+
+        assert_eq!(5, moves.len());
+        assert_eq!(true, moves.contains(&Move::Place(queen(), Axial::zero())));
+    }
+
+    #[test]
+    fn placements_and_movements() {
+        let b = board_with_players(vec![
+            (queen(), Axial::zero(), PlayerNumber::One),
+            (queen(), Axial::zero().south(), PlayerNumber::Two),
+        ]);
+        let moves = b.legal_moves(PlayerNumber::One, &[ant(), ant()]);
+
+        assert_eq!(5, moves.len());
+        assert_eq!(
+            true,
+            moves.contains(&Move::Place(ant(), Axial::zero().north()))
+        );
+        assert_eq!(
+            true,
+            moves.contains(&Move::Move(Axial::zero(), Axial::zero().south_west()))
+        );
+    }
+
+    #[test]
+    fn pass_when_nothing_else_is_legal() {
+        let b = board_with_players(vec![
+            (ant(), Axial::zero(), PlayerNumber::Two),
+            (queen(), Axial::zero().south(), PlayerNumber::One),
+            (ant(), Axial::zero().south().south(), PlayerNumber::Two),
+        ]);
+        assert_eq!(vec![Move::Pass], b.legal_moves(PlayerNumber::One, &[]));
+    }
+}
+
+#[cfg(test)]
+mod turns {
+    use super::helpers::*;
+    use super::*;
+    use tile::{ant, beetle, grass_hopper, queen, spider};
+
+    fn board_without_queens() -> Board {
+        let mut b = Board::new();
+        b.place_tile(ant(), Axial::zero(), PlayerNumber::One)
+            .unwrap();
+        b.place_tile(ant(), Axial::zero().south(), PlayerNumber::Two)
+            .unwrap();
+        b.place_tile(spider(), Axial::zero().north(), PlayerNumber::One)
+            .unwrap();
+        b.place_tile(spider(), Axial::zero().south().south(), PlayerNumber::Two)
+            .unwrap();
+        b.place_tile(beetle(), Axial::zero().north().north(), PlayerNumber::One)
+            .unwrap();
+        b.place_tile(
+            beetle(),
+            Axial::zero().south().south().south(),
+            PlayerNumber::Two,
+        )
+        .unwrap();
+        b
+    }
+
+    #[test]
+    fn current_player() {
+        let mut b = Board::new();
+        assert_eq!(PlayerNumber::One, b.current_player());
+        b.place_tile(queen(), Axial::zero(), PlayerNumber::One)
+            .unwrap();
+        assert_eq!(PlayerNumber::Two, b.current_player());
+        assert_eq!(1, b.turns_taken(PlayerNumber::One));
+        assert_eq!(0, b.turns_taken(PlayerNumber::Two));
+    }
+
+    #[test]
+    fn cannot_move_before_queen_placed() {
+        let b = board_without_queens();
+        let moves = b.legal_moves(PlayerNumber::One, &[]);
+        assert_eq!(vec![Move::Pass], moves);
+    }
+
+    #[test]
+    fn queen_must_be_placed_on_fourth_turn() {
+        let b = board_without_queens();
+        let moves = b.legal_moves(PlayerNumber::One, &[grass_hopper(), queen()]);
+
+        assert_eq!(false, moves.is_empty());
+        assert_eq!(
+            true,
+            moves.iter().all(|m| match m {
+                Move::Place(tile, _) => tile.kind() == TileKind::Queen,
+                _ => false,
+            })
+        );
+    }
+
+    #[test]
+    fn place_other_tile_on_fourth_turn() {
+        let mut b = board_without_queens();
+        assert_eq!(
+            Err(MoveError::QueenMustBePlaced),
+            b.place_tile(
+                grass_hopper(),
+                Axial::zero().north().north().north(),
+                PlayerNumber::One,
+            )
+        );
+    }
+
+    #[test]
+    fn earlier_queen_deadline() {
+        let mut b = Board::with_rules(RuleSet {
+            queen_deadline: 2,
+            ..RuleSet::default()
+        });
+        b.place_tile(ant(), Axial::zero(), PlayerNumber::One)
+            .unwrap();
+        b.place_tile(ant(), Axial::zero().south(), PlayerNumber::Two)
+            .unwrap();
+        assert_eq!(
+            Err(MoveError::QueenMustBePlaced),
+            b.place_tile(spider(), Axial::zero().north(), PlayerNumber::One)
+        );
+    }
+
+    #[test]
+    fn tournament_opening() {
+        let mut b = Board::with_rules(RuleSet {
+            tournament_opening: true,
+            ..RuleSet::default()
+        });
+        assert_eq!(
+            vec![Move::Place(ant(), Axial::zero())],
+            b.legal_moves(PlayerNumber::One, &[queen(), ant()])
+        );
+        assert_eq!(
+            Err(MoveError::TournamentOpening),
+            b.place_tile(queen(), Axial::zero(), PlayerNumber::One)
+        );
+
+        b.place_tile(ant(), Axial::zero(), PlayerNumber::One)
+            .unwrap();
+        b.place_tile(ant(), Axial::zero().south(), PlayerNumber::Two)
+            .unwrap();
+        assert_eq!(
+            Ok(()),
+            b.place_tile(queen(), Axial::zero().north(), PlayerNumber::One)
+        );
+    }
+
+    #[test]
+    fn move_before_queen_placed() {
+        let mut b = board_with(vec![(ant(), Axial::zero()), (ant(), Axial::zero().south())]);
+        assert_eq!(
+            Err(MoveError::QueenNotPlaced),
+            b.move_tile(Axial::zero(), Axial::zero().south_east())
+        );
+    }
+}
+
+#[cfg(test)]
+mod errors {
+    use super::helpers::*;
+    use super::*;
+    use tile::{ant, queen};
+
+    #[test]
+    fn not_your_turn() {
+        let mut b = Board::new();
+        assert_eq!(
+            Err(MoveError::NotYourTurn),
+            b.place_tile(queen(), Axial::zero(), PlayerNumber::Two)
+        );
+    }
+
+    #[test]
+    fn occupied_hex() {
+        let mut b = board_with_tiles_at(vec![Axial::zero(), Axial::zero().south()]);
+        assert_eq!(
+            Err(MoveError::OccupiedHex),
+            b.place_tile(ant(), Axial::zero(), PlayerNumber::One)
+        );
+    }
+
+    #[test]
+    fn touches_opponent() {
+        let mut b = board_with_tiles_at(vec![Axial::zero(), Axial::zero().south()]);
+        assert_eq!(
+            Err(MoveError::TouchesOpponent),
+            b.place_tile(ant(), Axial::zero().south_east(), PlayerNumber::One)
+        );
+    }
+
+    #[test]
+    fn not_touching_hive() {
+        let mut b = board_with_tiles_at(vec![Axial::zero(), Axial::zero().south()]);
+        assert_eq!(
+            Err(MoveError::NotTouchingHive),
+            b.place_tile(ant(), Axial::new(5, 5), PlayerNumber::One)
+        );
+    }
+
+    #[test]
+    fn breaks_hive() {
+        let mut b = board_with(vec![
+            (ant(), Axial::zero()),
+            (queen(), Axial::zero().south()),
+            (ant(), Axial::zero().south().south()),
+        ]);
+        assert_eq!(
+            Err(MoveError::BreaksHive),
+            b.move_tile(Axial::zero().south(), Axial::zero().south_east())
+        );
+    }
+
+    #[test]
+    fn no_tile_to_move() {
+        let mut b = board_with(vec![(queen(), Axial::zero())]);
+        assert_eq!(
+            Err(MoveError::NoTileToMove),
+            b.move_tile(Axial::zero().south(), Axial::zero().south_east())
+        );
+    }
+}
+
+#[cfg(test)]
+mod undo {
+    use super::*;
+    use tile::{ant, beetle, queen};
+
+    fn board_after_moves() -> Board {
+        let mut b = Board::new();
+        b.place_tile(queen(), Axial::zero(), PlayerNumber::One)
+            .unwrap();
+        b.place_tile(queen(), Axial::zero().south(), PlayerNumber::Two)
+            .unwrap();
+        b.place_tile(beetle(), Axial::zero().north(), PlayerNumber::One)
+            .unwrap();
+        b.place_tile(ant(), Axial::zero().south().south(), PlayerNumber::Two)
+            .unwrap();
+        b.move_tile(Axial::zero().north(), Axial::zero()).unwrap();
+        b
+    }
+
+    #[test]
+    fn undo_move_onto_stack() {
+        let mut b = board_after_moves();
+        assert_eq!(
+            Some(Move::Move(Axial::zero().north(), Axial::zero())),
+            b.undo()
+        );
+        assert_eq!(1, b.height(&Axial::zero()));
+        assert_eq!(
+            TileKind::Beetle,
+            b.top_tile(&Axial::zero().north()).unwrap().tile.kind()
+        );
+        assert_eq!(4, b.turn());
+        assert_eq!(PlayerNumber::One, b.current_player());
+    }
+
+    #[test]
+    fn undo_everything() {
+        let mut b = board_after_moves();
+        while b.undo().is_some() {}
+
+        assert_eq!(0, b.turn());
+        assert_eq!(false, b.is_occupied(&Axial::zero()));
+        assert_eq!(0, b.history().len());
+    }
+
+    #[test]
+    fn redo() {
+        let mut b = board_after_moves();
+        b.undo();
+        b.undo();
+        b.undo();
+
+        assert_eq!(Some(Move::Place(beetle(), Axial::zero().north())), b.redo());
+        assert_eq!(
+            Some(Move::Place(ant(), Axial::zero().south().south())),
+            b.redo()
+        );
+        assert_eq!(
+            PlayerNumber::Two,
+            b.top_tile(&Axial::zero().south().south())
+                .unwrap()
+                .id
+                .player
+        );
+        assert_eq!(
+            Some(Move::Move(Axial::zero().north(), Axial::zero())),
+            b.redo()
+        );
+        assert_eq!(None, b.redo());
+        assert_eq!(2, b.height(&Axial::zero()));
+    }
+
+    #[test]
+    fn new_move_clears_redo() {
+        let mut b = board_after_moves();
+        b.undo();
+        b.move_tile(Axial::zero().north(), Axial::zero().north_east())
+            .unwrap();
+        assert_eq!(None, b.redo());
+    }
+}
+
+#[cfg(test)]
+mod throws {
+    use super::helpers::*;
+    use super::*;
+    use tile::{ant, pillbug, queen};
+
+    fn board_with_pillbug() -> Board {
+        board_with_players(vec![
+            (pillbug(), Axial::zero(), PlayerNumber::One),
+            (queen(), Axial::zero().north(), PlayerNumber::One),
+            (ant(), Axial::zero().south(), PlayerNumber::Two),
+            (queen(), Axial::zero().south_east(), PlayerNumber::Two),
+        ])
+    }
+
+    #[test]
+    fn legal_moves_include_throws() {
+        let b = board_with_pillbug();
+        let moves = b.legal_moves(PlayerNumber::One, &[]);
+        assert_eq!(
+            true,
+            moves.contains(&Move::Throw(
+                Axial::zero().south(),
+                Axial::zero().north_west()
+            ))
+        );
+    }
+
+    #[test]
+    fn thrown_tile_is_frozen() {
+        let mut b = board_with_pillbug();
+        b.throw_tile(Axial::zero().south(), Axial::zero().north_west())
+            .unwrap();
+
+        assert_eq!(
+            TileKind::Ant,
+            b.top_tile(&Axial::zero().north_west()).unwrap().tile.kind()
+        );
+        assert_eq!(0, b.possible_moves(Axial::zero().north_west()).len());
+        assert_eq!(
+            Err(MoveError::IllegalMove),
+            b.move_tile(Axial::zero().north_west(), Axial::zero().south_west())
+        );
+
+        b.pass();
+        b.pass();
+        assert_ne!(0, b.possible_moves(Axial::zero().north_west()).len());
+    }
+
+    #[test]
+    fn cannot_throw_tile_just_moved() {
+        let mut b = board_with_players(vec![
+            (pillbug(), Axial::zero(), PlayerNumber::One),
+            (queen(), Axial::zero().north(), PlayerNumber::One),
+            (queen(), Axial::zero().south_east(), PlayerNumber::Two),
+            (ant(), Axial::new(1, 1), PlayerNumber::Two),
+        ]);
+        b.pass();
+        b.move_tile(Axial::new(1, 1), Axial::zero().south())
+            .unwrap();
+
+        assert_eq!(
+            false,
+            b.possible_throws(Axial::zero())
+                .iter()
+                .any(|(from, _)| *from == Axial::zero().south())
+        );
+        assert_eq!(
+            Err(MoveError::IllegalMove),
+            b.throw_tile(Axial::zero().south(), Axial::zero().north_west())
+        );
+    }
+
+    #[test]
+    fn cannot_throw_with_opponents_pillbug() {
+        let mut b = board_with_pillbug();
+        b.pass();
+        assert_eq!(
+            Err(MoveError::IllegalMove),
+            b.throw_tile(Axial::zero().south(), Axial::zero().north_west())
+        );
+    }
+
+    #[test]
+    fn undo_throw() {
+        let mut b = board_with_pillbug();
+        b.throw_tile(Axial::zero().south(), Axial::zero().north_west())
+            .unwrap();
+        b.undo();
+
+        assert_eq!(false, b.is_occupied(&Axial::zero().north_west()));
+        let placed_tile = b.top_tile(&Axial::zero().south()).unwrap();
+        assert_eq!(TileKind::Ant, placed_tile.tile.kind());
+        assert_eq!(None, placed_tile.last_moved);
+        assert_eq!(false, placed_tile.thrown);
+    }
+}
+
+#[cfg(test)]
+mod position_key {
+    use super::helpers::*;
+    use super::*;
+    use tile::{ant, beetle, queen};
+
+    #[test]
+    fn ignores_translation() {
+        let at = |origin: Axial| {
+            board_with_players(vec![
+                (queen(), origin, PlayerNumber::One),
+                (ant(), origin.south(), PlayerNumber::Two),
+                (beetle(), origin.south(), PlayerNumber::One),
+            ])
+            .position_key()
+        };
+        assert_eq!(at(Axial::zero()), at(Axial::new(3, -7)));
+    }
+
+    #[test]
+    fn different_stacking_order() {
+        let a = board_with_players(vec![
+            (queen(), Axial::zero(), PlayerNumber::One),
+            (ant(), Axial::zero().south(), PlayerNumber::Two),
+            (beetle(), Axial::zero().south(), PlayerNumber::One),
+        ]);
+        let b = board_with_players(vec![
+            (queen(), Axial::zero(), PlayerNumber::One),
+            (beetle(), Axial::zero().south(), PlayerNumber::One),
+            (ant(), Axial::zero().south(), PlayerNumber::Two),
+        ]);
+        assert_ne!(a.position_key(), b.position_key());
+    }
+
+    #[test]
+    fn side_to_move() {
+        let mut b = board_with(vec![(queen(), Axial::zero())]);
+        let before = b.position_key();
+        b.pass();
+        assert_ne!(before, b.position_key());
+    }
+}
+
+#[cfg(test)]
+mod piece_ids {
+    use super::*;
+    use tile::{ant, beetle, queen};
+
+    #[test]
+    fn numbered_in_placement_order() {
+        let mut b = Board::new();
+        b.place_tile(ant(), Axial::zero(), PlayerNumber::One)
+            .unwrap();
+        b.place_tile(ant(), Axial::zero().south(), PlayerNumber::Two)
+            .unwrap();
+        b.place_tile(ant(), Axial::zero().north(), PlayerNumber::One)
+            .unwrap();
+
+        let id = |position: Axial| b.top_tile(&position).unwrap().id.to_string();
+        assert_eq!("wA1", id(Axial::zero()));
+        assert_eq!("bA1", id(Axial::zero().south()));
+        assert_eq!("wA2", id(Axial::zero().north()));
+    }
+
+    #[test]
+    fn kept_when_moved() {
+        let mut b = Board::new();
+        b.place_tile(queen(), Axial::zero(), PlayerNumber::One)
+            .unwrap();
+        b.place_tile(queen(), Axial::zero().south(), PlayerNumber::Two)
+            .unwrap();
+        b.place_tile(beetle(), Axial::zero().north(), PlayerNumber::One)
+            .unwrap();
+        b.pass();
+        b.move_tile(Axial::zero().north(), Axial::zero()).unwrap();
+
+        let stack = &b.tiles[&Axial::zero()];
+        assert_eq!("wQ", stack[0].id.to_string());
+        assert_eq!("wB1", stack[1].id.to_string());
+        assert_eq!(false, stack[0] == stack[1]);
+    }
+
+    #[test]
+    fn renumbered_after_undo() {
+        let mut b = Board::new();
+        b.place_tile(ant(), Axial::zero(), PlayerNumber::One)
+            .unwrap();
+        b.place_tile(ant(), Axial::zero().south(), PlayerNumber::Two)
+            .unwrap();
+        b.undo();
+        b.undo();
+        b.place_tile(ant(), Axial::zero(), PlayerNumber::One)
+            .unwrap();
+        assert_eq!("wA1", b.top_tile(&Axial::zero()).unwrap().id.to_string());
+    }
+}
@@ -1,59 +1,529 @@
 use axial::Axial;
 use board::*;
+use move_error::MoveError;
+use moves::Move;
 use player::PlayerNumber;
 use player::*;
+use rule_set::RuleSet;
 use std::rc::Rc;
 use tile::*;
 
+// The game is drawn when the same position comes up this many times
+static REPETITIONS_TO_DRAW: usize = 3;
+
+// Players that always pick the same move can shuffle back and forth forever
+static GO_TURN_LIMIT: usize = 100;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum GameState {
+    InProgress,
+    Won(PlayerNumber),
+    Draw,
+}
+
 pub struct Game {
     player1: Player,
     player2: Player,
     board: Board,
+    state: GameState,
+    // Whether a player with nothing to do passes straight away
+    auto_pass: bool,
 }
 
 impl Game {
-    pub fn new(p1: Player, p2: Player) -> Game {
+    pub fn new(rules: RuleSet) -> Game {
         Game {
-            player1: p1,
-            player2: p2,
-            board: Board::new(),
+            player1: Player::new(rules.tiles(), PlayerNumber::One),
+            player2: Player::new(rules.tiles(), PlayerNumber::Two),
+            board: Board::with_rules(rules),
+            state: GameState::InProgress,
+            auto_pass: true,
         }
     }
 
-    pub fn go(&mut self) {
-        self.board
-            .place_tile(queen(), Axial::zero(), PlayerNumber::One);
-        self.board
-            .place_tile(beetle(), Axial::zero().south(), PlayerNumber::One);
+    pub fn set_auto_pass(&mut self, auto_pass: bool) {
+        self.auto_pass = auto_pass;
+    }
+
+    pub fn state(&self) -> GameState {
+        self.state
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn player(&self, number: PlayerNumber) -> &Player {
+        match number {
+            PlayerNumber::One => &self.player1,
+            PlayerNumber::Two => &self.player2,
+        }
+    }
+
+    fn player_mut(&mut self, number: PlayerNumber) -> &mut Player {
+        match number {
+            PlayerNumber::One => &mut self.player1,
+            PlayerNumber::Two => &mut self.player2,
+        }
+    }
+
+    pub fn legal_moves(&self) -> Vec<Move> {
+        let player = self.board.current_player();
         self.board
-            .place_tile(spider(), Axial::zero().south().south(), PlayerNumber::One);
-        self.board.place_tile(
-            ant(),
-            Axial::zero().south().south().south(),
-            PlayerNumber::One,
+            .legal_moves(player, &self.player(player).reserve())
+    }
+
+    pub fn play(&mut self, m: Move) -> Result<(), MoveError> {
+        if self.state != GameState::InProgress {
+            return Err(MoveError::GameOver);
+        }
+
+        let player = self.board.current_player();
+        match m {
+            Move::Place(tile, position) => {
+                let tile = self
+                    .player(player)
+                    .reserve()
+                    .iter()
+                    .find(|reserve_tile| reserve_tile.kind() == tile.kind())
+                    .cloned()
+                    .ok_or(MoveError::NotInReserve)?;
+                self.board.place_tile(tile.clone(), position, player)?;
+                self.player_mut(player).take_tile(tile.kind());
+            }
+            Move::Move(from, to) => self.board.move_tile(from, to)?,
+            Move::Throw(from, to) => self.board.throw_tile(from, to)?,
+            Move::Pass => {
+                if self.legal_moves() != vec![Move::Pass] {
+                    return Err(MoveError::CannotPass);
+                }
+                self.board.pass();
+            }
+        }
+        self.update_state();
+        Ok(())
+    }
+
+    // Undoing a move takes the resignation back too
+    pub fn resign(&mut self, player: PlayerNumber) {
+        if self.state == GameState::InProgress {
+            self.state = GameState::Won(player.other());
+        }
+    }
+
+    // Automatic passes are undone along with the move that forced them
+    pub fn undo(&mut self) -> Option<Move> {
+        loop {
+            let m = self.board.undo()?;
+            if let Move::Place(ref tile, _) = m {
+                let player = self.board.current_player();
+                self.player_mut(player).return_tile(tile.kind());
+            }
+            if m != Move::Pass || !self.auto_pass {
+                self.state = self.result();
+                return Some(m);
+            }
+        }
+    }
+
+    pub fn redo(&mut self) -> Option<Move> {
+        let m = self.board.redo()?;
+        if let Move::Place(ref tile, _) = m {
+            let player = self.board.current_player().other();
+            self.player_mut(player).take_tile(tile.kind());
+        }
+        self.update_state();
+        Some(m)
+    }
+
+    fn result(&self) -> GameState {
+        match (
+            self.board.is_queen_surrounded(PlayerNumber::One),
+            self.board.is_queen_surrounded(PlayerNumber::Two),
+        ) {
+            (true, true) => GameState::Draw,
+            (true, false) => GameState::Won(PlayerNumber::Two),
+            (false, true) => GameState::Won(PlayerNumber::One),
+            (false, false) => match self.board.rules().move_limit {
+                Some(limit) if self.board.turn() >= limit => GameState::Draw,
+                _ if self.board.repetitions() >= REPETITIONS_TO_DRAW => GameState::Draw,
+                _ => GameState::InProgress,
+            },
+        }
+    }
+
+    fn update_state(&mut self) {
+        self.state = self.result();
+
+        // If neither player can do anything the game can never finish
+        if self.state != GameState::InProgress || self.legal_moves() != vec![Move::Pass] {
+            return;
+        }
+        if self.auto_pass {
+            self.board.pass();
+            if self.legal_moves() == vec![Move::Pass] {
+                self.state = GameState::Draw;
+            }
+        } else {
+            let other = self.board.current_player().other();
+            if self.board.legal_moves(other, &self.player(other).reserve()) == vec![Move::Pass] {
+                self.state = GameState::Draw;
+            }
+        }
+    }
+
+    pub fn go(&mut self) {
+        while self.state == GameState::InProgress && self.board.turn() < GO_TURN_LIMIT {
+            let m = self
+                .player(self.board.current_player())
+                .get_move(&self.board);
+            self.play(m).expect("player chose an illegal move");
+        }
+
+        self.board.render();
+    }
+}
+
+#[cfg(test)]
+pub mod helpers {
+    use super::*;
+    use board::helpers::board_with_players;
+
+    // Neither player has anything left in reserve
+    pub fn game_with(tiles: Vec<(Rc<Tile>, Axial, PlayerNumber)>) -> Game {
+        game_with_auto_pass(tiles, true)
+    }
+
+    pub fn game_with_auto_pass(
+        tiles: Vec<(Rc<Tile>, Axial, PlayerNumber)>,
+        auto_pass: bool,
+    ) -> Game {
+        let mut g = Game::new(RuleSet::default());
+        g.set_auto_pass(auto_pass);
+        g.player1 = Player::new(vec![], PlayerNumber::One);
+        g.player2 = Player::new(vec![], PlayerNumber::Two);
+        g.board = board_with_players(tiles);
+        g.update_state();
+        g
+    }
+}
+
+#[cfg(test)]
+mod state {
+    use super::helpers::*;
+    use super::*;
+
+    fn surrounding(position: Axial, player: PlayerNumber) -> Vec<(Rc<Tile>, Axial, PlayerNumber)> {
+        position
+            .surrounding_positions()
+            .into_iter()
+            .map(|surrounding_position| (ant(), surrounding_position, player))
+            .collect()
+    }
+
+    #[test]
+    fn in_progress() {
+        let g = game_with(vec![
+            (queen(), Axial::zero(), PlayerNumber::One),
+            (queen(), Axial::zero().south(), PlayerNumber::Two),
+        ]);
+        assert_eq!(GameState::InProgress, g.state());
+    }
+
+    #[test]
+    fn resign() {
+        let mut g = Game::new(RuleSet::default());
+        g.play(Move::Place(queen(), Axial::zero())).unwrap();
+        g.resign(PlayerNumber::Two);
+        assert_eq!(GameState::Won(PlayerNumber::One), g.state());
+        assert_eq!(
+            Err(MoveError::GameOver),
+            g.play(Move::Place(queen(), Axial::zero().south()))
         );
-        self.board.place_tile(
-            ant(),
-            Axial::zero().south().south().south().south_west(),
-            PlayerNumber::One,
+        g.undo();
+        assert_eq!(GameState::InProgress, g.state());
+    }
+
+    #[test]
+    fn queen_surrounded() {
+        let mut tiles = vec![(queen(), Axial::zero(), PlayerNumber::One)];
+        tiles.extend(surrounding(Axial::zero(), PlayerNumber::Two));
+        tiles.push((queen(), Axial::zero().north().north(), PlayerNumber::Two));
+        let g = game_with(tiles);
+        assert_eq!(GameState::Won(PlayerNumber::Two), g.state());
+    }
+
+    #[test]
+    fn both_queens_surrounded() {
+        let mut tiles = vec![
+            (queen(), Axial::zero(), PlayerNumber::One),
+            (queen(), Axial::new(1, 1), PlayerNumber::Two),
+        ];
+        tiles.extend(surrounding(Axial::zero(), PlayerNumber::One));
+        tiles.extend(
+            surrounding(Axial::new(1, 1), PlayerNumber::Two)
+                .into_iter()
+                .filter(|(_, position, _)| {
+                    !Axial::zero().surrounding_positions().contains(position)
+                }),
+        );
+        let g = game_with(tiles);
+        assert_eq!(GameState::Draw, g.state());
+    }
+
+    #[test]
+    fn pass_when_nothing_else_is_legal() {
+        let mut g = game_with(vec![
+            (queen(), Axial::zero(), PlayerNumber::One),
+            (queen(), Axial::zero().south(), PlayerNumber::Two),
+            (ant(), Axial::zero().south().south(), PlayerNumber::Two),
+            (
+                ant(),
+                Axial::zero().south().south().south(),
+                PlayerNumber::One,
+            ),
+        ]);
+        g.player1 = Player::new(vec![ant()], PlayerNumber::One);
+
+        // Player two's tiles are pinned and they have nothing left to place
+        g.play(Move::Place(ant(), Axial::zero().north())).unwrap();
+        assert_eq!(PlayerNumber::One, g.board().current_player());
+        assert_eq!(GameState::InProgress, g.state());
+    }
+
+    #[test]
+    fn move_limit() {
+        let mut g = Game::new(RuleSet {
+            move_limit: Some(2),
+            ..RuleSet::default()
+        });
+        g.play(Move::Place(queen(), Axial::zero())).unwrap();
+        assert_eq!(GameState::InProgress, g.state());
+        g.play(Move::Place(queen(), Axial::zero().south())).unwrap();
+        assert_eq!(GameState::Draw, g.state());
+
+        g.undo();
+        assert_eq!(GameState::InProgress, g.state());
+    }
+
+    #[test]
+    fn threefold_repetition() {
+        let mut g = game_with(vec![
+            (queen(), Axial::zero(), PlayerNumber::One),
+            (queen(), Axial::zero().south(), PlayerNumber::Two),
+            (ant(), Axial::zero().north(), PlayerNumber::One),
+            (ant(), Axial::zero().south().south(), PlayerNumber::Two),
+        ]);
+        let shuffle = vec![
+            Move::Move(Axial::zero().north(), Axial::zero().north_east()),
+            Move::Move(Axial::zero().south().south(), Axial::new(1, 1)),
+            Move::Move(Axial::zero().north_east(), Axial::zero().north()),
+            Move::Move(Axial::new(1, 1), Axial::zero().south().south()),
+        ];
+        for _ in 0..2 {
+            for m in &shuffle {
+                g.play(m.clone()).unwrap();
+            }
+        }
+        assert_eq!(GameState::InProgress, g.state());
+
+        g.play(shuffle[0].clone()).unwrap();
+        assert_eq!(GameState::Draw, g.state());
+        g.undo();
+        assert_eq!(GameState::InProgress, g.state());
+    }
+
+    #[test]
+    fn pass_without_auto_pass() {
+        let mut g = game_with_auto_pass(
+            vec![
+                (queen(), Axial::zero(), PlayerNumber::One),
+                (queen(), Axial::zero().south(), PlayerNumber::Two),
+                (ant(), Axial::zero().south().south(), PlayerNumber::Two),
+                (
+                    ant(),
+                    Axial::zero().south().south().south(),
+                    PlayerNumber::One,
+                ),
+            ],
+            false,
+        );
+        g.player1 = Player::new(vec![ant()], PlayerNumber::One);
+
+        g.play(Move::Place(ant(), Axial::zero().north())).unwrap();
+        assert_eq!(PlayerNumber::Two, g.board().current_player());
+        assert_eq!(vec![Move::Pass], g.legal_moves());
+        g.play(Move::Pass).unwrap();
+        assert_eq!(PlayerNumber::One, g.board().current_player());
+
+        assert_eq!(Some(Move::Pass), g.undo());
+        assert_eq!(PlayerNumber::Two, g.board().current_player());
+    }
+
+    #[test]
+    fn both_stuck_without_auto_pass() {
+        let g = game_with_auto_pass(
+            vec![
+                (ant(), Axial::zero(), PlayerNumber::Two),
+                (queen(), Axial::zero().south(), PlayerNumber::One),
+                (ant(), Axial::zero().south().south(), PlayerNumber::Two),
+            ],
+            false,
+        );
+        assert_eq!(GameState::Draw, g.state());
+        assert_eq!(0, g.board().history().len());
+    }
+
+    #[test]
+    fn play_after_game_over() {
+        let mut tiles = vec![(queen(), Axial::zero(), PlayerNumber::One)];
+        tiles.extend(surrounding(Axial::zero(), PlayerNumber::Two));
+        tiles.push((queen(), Axial::zero().north().north(), PlayerNumber::Two));
+        let mut g = game_with(tiles);
+        assert_eq!(Err(MoveError::GameOver), g.play(Move::Pass));
+    }
+}
+
+#[cfg(test)]
+mod play {
+    use super::*;
+
+    fn new_game() -> Game {
+        Game::new(RuleSet::default())
+    }
+
+    #[test]
+    fn alternates_players() {
+        let mut g = new_game();
+        g.play(Move::Place(queen(), Axial::zero())).unwrap();
+        assert_eq!(PlayerNumber::Two, g.board().current_player());
+        g.play(Move::Place(queen(), Axial::zero().south())).unwrap();
+        assert_eq!(PlayerNumber::One, g.board().current_player());
+    }
+
+    #[test]
+    fn placing_takes_tile_from_reserve() {
+        let mut g = new_game();
+        g.play(Move::Place(queen(), Axial::zero())).unwrap();
+        assert_eq!(
+            0,
+            g.player(PlayerNumber::One).reserve_count(TileKind::Queen)
         );
-        self.board.place_tile(
-            ant(),
-            Axial::zero().south().south().south().north_west(),
-            PlayerNumber::One,
+        assert_eq!(
+            1,
+            g.player(PlayerNumber::Two).reserve_count(TileKind::Queen)
         );
-        self.board.place_tile(
-            ant(),
-            Axial::zero().south().south().south().south_east(),
-            PlayerNumber::One,
+    }
+
+    #[test]
+    fn place_tile_not_in_reserve() {
+        let mut g = new_game();
+        g.play(Move::Place(queen(), Axial::zero())).unwrap();
+        g.play(Move::Place(queen(), Axial::zero().south())).unwrap();
+        assert_eq!(
+            Err(MoveError::NotInReserve),
+            g.play(Move::Place(queen(), Axial::zero().north()))
         );
+    }
 
-        self.board.place_tile(
-            grass_hopper(),
-            Axial::zero().south().north_east(),
-            PlayerNumber::One,
+    #[test]
+    fn move_opponents_tile() {
+        let mut g = new_game();
+        g.play(Move::Place(queen(), Axial::zero())).unwrap();
+        g.play(Move::Place(queen(), Axial::zero().south())).unwrap();
+        assert_eq!(
+            Err(MoveError::NotYourTurn),
+            g.play(Move::Move(
+                Axial::zero().south(),
+                Axial::zero().south_east()
+            ))
         );
+    }
 
-        self.board.render();
+    #[test]
+    fn pass_with_moves_available() {
+        let mut g = new_game();
+        assert_eq!(Err(MoveError::CannotPass), g.play(Move::Pass));
+    }
+}
+
+#[cfg(test)]
+mod go {
+    use super::*;
+
+    #[test]
+    fn finishes() {
+        let mut g = Game::new(RuleSet::default());
+        g.go();
+        assert_eq!(
+            true,
+            g.state() != GameState::InProgress || g.board().turn() >= GO_TURN_LIMIT
+        );
+    }
+}
+
+#[cfg(test)]
+mod undo {
+    use super::*;
+
+    fn new_game() -> Game {
+        Game::new(RuleSet::default())
+    }
+
+    #[test]
+    fn returns_tile_to_reserve() {
+        let mut g = new_game();
+        g.play(Move::Place(queen(), Axial::zero())).unwrap();
+        g.play(Move::Place(ant(), Axial::zero().south())).unwrap();
+
+        assert_eq!(Some(Move::Place(ant(), Axial::zero().south())), g.undo());
+        assert_eq!(3, g.player(PlayerNumber::Two).reserve_count(TileKind::Ant));
+        assert_eq!(
+            0,
+            g.player(PlayerNumber::One).reserve_count(TileKind::Queen)
+        );
+        assert_eq!(PlayerNumber::Two, g.board().current_player());
+    }
+
+    #[test]
+    fn redo_takes_tile_from_reserve() {
+        let mut g = new_game();
+        g.play(Move::Place(queen(), Axial::zero())).unwrap();
+        g.undo();
+        assert_eq!(Some(Move::Place(queen(), Axial::zero())), g.redo());
+        assert_eq!(
+            0,
+            g.player(PlayerNumber::One).reserve_count(TileKind::Queen)
+        );
+        assert_eq!(None, g.redo());
+    }
+
+    #[test]
+    fn undo_forced_pass() {
+        let mut g = new_game();
+        g.player2 = Player::new(vec![queen()], PlayerNumber::Two);
+        g.play(Move::Place(queen(), Axial::zero())).unwrap();
+        g.play(Move::Place(queen(), Axial::zero().south())).unwrap();
+        g.play(Move::Place(ant(), Axial::zero().north())).unwrap();
+        g.play(Move::Move(Axial::zero().south(), Axial::new(1, 0)))
+            .unwrap();
+
+        // Player two's queen can't move without breaking the hive
+        g.play(Move::Move(Axial::zero().north(), Axial::new(2, 0)))
+            .unwrap();
+        assert_eq!(PlayerNumber::One, g.board().current_player());
+        assert_eq!(Move::Pass, *g.board().history().last().unwrap());
+
+        assert_eq!(
+            Some(Move::Move(Axial::zero().north(), Axial::new(2, 0))),
+            g.undo()
+        );
+        assert_eq!(PlayerNumber::One, g.board().current_player());
+        assert_eq!(
+            Some(Move::Move(Axial::zero().north(), Axial::new(2, 0))),
+            g.redo()
+        );
+        assert_eq!(PlayerNumber::One, g.board().current_player());
+        assert_eq!(Move::Pass, *g.board().history().last().unwrap());
     }
 }
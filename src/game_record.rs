@@ -0,0 +1,341 @@
+use game::{Game, GameState};
+use game_string::{game_state, game_type, move_strings, parse_game_type, replay, GameStringError};
+use player::PlayerNumber;
+use rule_set::RuleSet;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+// A finished or in progress game along with who played it and when. On disk
+// it's a `Key: value` header, a blank line, then one move per line
+pub struct GameRecord {
+    pub white: String,
+    pub black: String,
+    pub date: String,
+    pub game: Game,
+}
+
+#[derive(Debug)]
+pub enum RecordError {
+    Io(io::Error),
+    MalformedHeader(String),
+    MissingHeader(String),
+    InvalidHeader(String, String),
+    Replay(GameStringError),
+    // The moves don't lead to the recorded result
+    ResultMismatch(String),
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RecordError::Io(error) => write!(f, "{}", error),
+            RecordError::MalformedHeader(line) => {
+                write!(f, "'{}' is not a 'Key: value' header", line)
+            }
+            RecordError::MissingHeader(key) => write!(f, "the record has no {} header", key),
+            RecordError::InvalidHeader(key, value) => {
+                write!(f, "'{}' is not a valid {} header", value, key)
+            }
+            RecordError::Replay(error) => write!(f, "{}", error),
+            RecordError::ResultMismatch(result) => {
+                write!(f, "the moves do not lead to the result '{}'", result)
+            }
+        }
+    }
+}
+
+impl From<io::Error> for RecordError {
+    fn from(error: io::Error) -> RecordError {
+        RecordError::Io(error)
+    }
+}
+
+impl GameRecord {
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        self.write(BufWriter::new(File::create(path)?))
+    }
+
+    pub fn load(path: &Path) -> Result<GameRecord, RecordError> {
+        GameRecord::read(BufReader::new(File::open(path)?))
+    }
+
+    // A header containing a line break would be read back as two headers
+    pub fn write<W: Write>(&self, mut output: W) -> io::Result<()> {
+        for (key, value) in &[
+            ("White", &self.white),
+            ("Black", &self.black),
+            ("Date", &self.date),
+        ] {
+            if value.contains(['\n', '\r']) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("the {} header can't contain a line break", key),
+                ));
+            }
+        }
+        let rules = self.game.board().rules();
+        let move_limit = match rules.move_limit {
+            Some(limit) => limit.to_string(),
+            None => "none".to_string(),
+        };
+        writeln!(output, "White: {}", self.white)?;
+        writeln!(output, "Black: {}", self.black)?;
+        writeln!(output, "Date: {}", self.date)?;
+        writeln!(output, "GameType: {}", game_type(rules))?;
+        writeln!(output, "TournamentOpening: {}", rules.tournament_opening)?;
+        writeln!(output, "QueenDeadline: {}", rules.queen_deadline)?;
+        writeln!(output, "MoveLimit: {}", move_limit)?;
+        writeln!(output, "Result: {}", game_state(&self.game))?;
+        writeln!(output)?;
+        for move_string in move_strings(self.game.board()) {
+            writeln!(output, "{}", move_string)?;
+        }
+        output.flush()
+    }
+
+    // Unknown headers are ignored
+    pub fn read<R: BufRead>(input: R) -> Result<GameRecord, RecordError> {
+        let mut lines = input.lines();
+        let mut headers = HashMap::new();
+        for line in lines.by_ref() {
+            let line = line?;
+            if line.trim().is_empty() {
+                break;
+            }
+            match line.find(':') {
+                Some(index) => {
+                    headers.insert(
+                        line[..index].trim().to_string(),
+                        line[index + 1..].trim().to_string(),
+                    );
+                }
+                None => return Err(RecordError::MalformedHeader(line)),
+            }
+        }
+        let header = |key: &str| {
+            headers
+                .get(key)
+                .cloned()
+                .ok_or_else(|| RecordError::MissingHeader(key.to_string()))
+        };
+        let invalid =
+            |key: &str, value: &str| RecordError::InvalidHeader(key.to_string(), value.to_string());
+
+        let game_type = header("GameType")?;
+        let tournament_opening = header("TournamentOpening")?;
+        let queen_deadline = header("QueenDeadline")?;
+        let move_limit = header("MoveLimit")?;
+        let rules = RuleSet {
+            tournament_opening: tournament_opening
+                .parse()
+                .map_err(|_| invalid("TournamentOpening", &tournament_opening))?,
+            queen_deadline: queen_deadline
+                .parse()
+                .map_err(|_| invalid("QueenDeadline", &queen_deadline))?,
+            move_limit: match move_limit.as_str() {
+                "none" => None,
+                limit => Some(
+                    limit
+                        .parse()
+                        .map_err(|_| invalid("MoveLimit", &move_limit))?,
+                ),
+            },
+            ..parse_game_type(&game_type).ok_or_else(|| invalid("GameType", &game_type))?
+        };
+
+        let mut moves = Vec::new();
+        for line in lines {
+            let line = line?;
+            if !line.trim().is_empty() {
+                moves.push(line.trim().to_string());
+            }
+        }
+        let moves: Vec<&str> = moves.iter().map(|m| m.as_str()).collect();
+        let mut game = replay(rules, &moves).map_err(RecordError::Replay)?;
+
+        // A win the moves don't reach is a resignation
+        let result = header("Result")?;
+        if game.state() == GameState::InProgress {
+            match result.as_str() {
+                "WhiteWins" => game.resign(PlayerNumber::Two),
+                "BlackWins" => game.resign(PlayerNumber::One),
+                _ => {}
+            }
+        }
+        if game_state(&game) != result {
+            return Err(RecordError::ResultMismatch(result));
+        }
+
+        Ok(GameRecord {
+            white: header("White")?,
+            black: header("Black")?,
+            date: header("Date")?,
+            game,
+        })
+    }
+}
+
+#[cfg(test)]
+mod read_write {
+    use super::*;
+    use axial::Axial;
+    use moves::Move;
+    use std::env;
+    use std::fs;
+    use std::process;
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use tile::{ant, queen};
+
+    fn record() -> GameRecord {
+        let mut game = Game::new(RuleSet {
+            ladybug: true,
+            move_limit: Some(100),
+            ..RuleSet::default()
+        });
+        game.play(Move::Place(queen(), Axial::zero())).unwrap();
+        game.play(Move::Place(ant(), Axial::zero().south()))
+            .unwrap();
+        GameRecord {
+            white: "Alice".to_string(),
+            black: "Bob".to_string(),
+            date: "2026-10-18".to_string(),
+            game,
+        }
+    }
+
+    fn written(record: &GameRecord) -> String {
+        let mut output = Vec::new();
+        record.write(&mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn write() {
+        assert_eq!(
+            "White: Alice\n\
+             Black: Bob\n\
+             Date: 2026-10-18\n\
+             GameType: Base+L\n\
+             TournamentOpening: false\n\
+             QueenDeadline: 4\n\
+             MoveLimit: 100\n\
+             Result: InProgress\n\
+             \n\
+             wQ\n\
+             bA1 /wQ\n",
+            written(&record())
+        );
+    }
+
+    #[test]
+    fn read() {
+        let s = written(&record());
+        let read = GameRecord::read(s.as_bytes()).unwrap();
+        assert_eq!("Alice", read.white);
+        assert_eq!("Bob", read.black);
+        assert_eq!("2026-10-18", read.date);
+        assert_eq!(*record().game.board().rules(), *read.game.board().rules());
+        assert_eq!(
+            record().game.board().position_key(),
+            read.game.board().position_key()
+        );
+        assert_eq!(s, written(&read));
+    }
+
+    #[test]
+    fn save_and_load() {
+        // Unique so parallel runs don't share the file
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .subsec_nanos();
+        let path = env::temp_dir().join(format!(
+            "rust-hive-save-and-load-{}-{}.txt",
+            process::id(),
+            nanos
+        ));
+        record().save(&path).unwrap();
+        let loaded = GameRecord::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(written(&record()), written(&loaded));
+    }
+
+    #[test]
+    fn line_break_in_header() {
+        let mut record = record();
+        record.black = "Bob\nResult: WhiteWins".to_string();
+        let error = record.write(Vec::new()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidInput, error.kind());
+        assert_eq!(
+            "the Black header can't contain a line break",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn missing_header() {
+        let s = written(&record()).replace("Date: 2026-10-18\n", "");
+        match GameRecord::read(s.as_bytes()) {
+            Err(RecordError::MissingHeader(key)) => assert_eq!("Date", key),
+            _ => panic!("expected a missing header"),
+        }
+    }
+
+    #[test]
+    fn invalid_header() {
+        let s = written(&record()).replace("QueenDeadline: 4", "QueenDeadline: soon");
+        match GameRecord::read(s.as_bytes()) {
+            Err(error) => assert_eq!(
+                "'soon' is not a valid QueenDeadline header",
+                error.to_string()
+            ),
+            _ => panic!("expected an invalid header"),
+        }
+    }
+
+    #[test]
+    fn result_mismatch() {
+        let s = written(&record()).replace("Result: InProgress", "Result: Draw");
+        match GameRecord::read(s.as_bytes()) {
+            Err(RecordError::ResultMismatch(result)) => assert_eq!("Draw", result),
+            _ => panic!("expected a result mismatch"),
+        }
+    }
+
+    #[test]
+    fn resigned() {
+        let mut resigned = record();
+        resigned.game.resign(PlayerNumber::One);
+        let s = written(&resigned);
+        let read = GameRecord::read(s.as_bytes()).unwrap();
+        assert_eq!(GameState::Won(PlayerNumber::Two), read.game.state());
+        assert_eq!(s, written(&read));
+    }
+
+    #[test]
+    fn resigned_before_moving() {
+        let mut resigned = record();
+        resigned.game = Game::new(RuleSet::default());
+        resigned.game.resign(PlayerNumber::One);
+        let s = written(&resigned);
+        let read = GameRecord::read(s.as_bytes()).unwrap();
+        assert_eq!(GameState::Won(PlayerNumber::Two), read.game.state());
+        assert_eq!(s, written(&read));
+    }
+
+    #[test]
+    fn illegal_move() {
+        let s = written(&record()) + "wA1 wQ\n";
+        match GameRecord::read(s.as_bytes()) {
+            Err(error) => assert_eq!(
+                "'wA1 wQ' is illegal, that position is already occupied",
+                error.to_string()
+            ),
+            _ => panic!("expected an illegal move"),
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use board::Board;
+use game::{Game, GameState};
+use move_error::MoveError;
+use notation::{format_move, parse_move, NotationError};
+use player::PlayerNumber;
+use rule_set::RuleSet;
+use std::fmt;
+
+// The expansions in play, `Base` or e.g. `Base+MP`
+pub fn game_type(rules: &RuleSet) -> String {
+    let expansions: String = vec![
+        (rules.mosquito, 'M'),
+        (rules.ladybug, 'L'),
+        (rules.pillbug, 'P'),
+    ]
+    .into_iter()
+    .filter(|(enabled, _)| *enabled)
+    .map(|(_, letter)| letter)
+    .collect();
+
+    if expansions.is_empty() {
+        "Base".to_string()
+    } else {
+        format!("Base+{}", expansions)
+    }
+}
+
+pub fn parse_game_type(s: &str) -> Option<RuleSet> {
+    let mut rules = RuleSet::default();
+    let expansions = if s == "Base" {
+        ""
+    } else {
+        match s.strip_prefix("Base+") {
+            Some(expansions) if !expansions.is_empty() => expansions,
+            _ => return None,
+        }
+    };
+    for letter in expansions.chars() {
+        let enabled = match letter {
+            'M' => &mut rules.mosquito,
+            'L' => &mut rules.ladybug,
+            'P' => &mut rules.pillbug,
+            _ => return None,
+        };
+        if *enabled {
+            return None;
+        }
+        *enabled = true;
+    }
+    Some(rules)
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum GameStringError {
+    InvalidGameType(String),
+    InvalidGameState(String),
+    InvalidTurn(String),
+    Notation(NotationError),
+    IllegalMove(String, MoveError),
+    // The game state or turn doesn't match the one reached by the moves
+    Mismatch(String),
+}
+
+impl fmt::Display for GameStringError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameStringError::InvalidGameType(s) => write!(f, "'{}' is not a valid game type", s),
+            GameStringError::InvalidGameState(s) => {
+                write!(f, "'{}' is not a valid game state", s)
+            }
+            GameStringError::InvalidTurn(s) => write!(f, "'{}' is not a valid turn", s),
+            GameStringError::Notation(error) => write!(f, "{}", error),
+            GameStringError::IllegalMove(m, error) => write!(f, "'{}' is illegal, {}", m, error),
+            GameStringError::Mismatch(s) => write!(f, "the moves do not lead to '{}'", s),
+        }
+    }
+}
+
+static GAME_STATES: &[&str] = &["NotStarted", "InProgress", "Draw", "WhiteWins", "BlackWins"];
+
+// `GameType;GameState;Turn;move1;move2;...`
+pub fn game_string(game: &Game) -> String {
+    let mut parts = vec![
+        game_type(game.board().rules()),
+        game_state(game),
+        turn(game.board()),
+    ];
+    parts.extend(move_strings(game.board()));
+    parts.join(";")
+}
+
+pub fn parse_game_string(s: &str) -> Result<Game, GameStringError> {
+    let parts: Vec<&str> = s.split(';').map(|part| part.trim()).collect();
+    let rules = parse_game_type(parts[0])
+        .ok_or_else(|| GameStringError::InvalidGameType(parts[0].to_string()))?;
+    let state = parts.get(1).cloned().unwrap_or("");
+    if !GAME_STATES.contains(&state) {
+        return Err(GameStringError::InvalidGameState(state.to_string()));
+    }
+    let expected_turn = parts.get(2).cloned().unwrap_or("");
+    if !is_turn(expected_turn) {
+        return Err(GameStringError::InvalidTurn(expected_turn.to_string()));
+    }
+
+    let game = replay(rules, &parts[3..])?;
+    if game_state(&game) != state || turn(game.board()) != expected_turn {
+        return Err(GameStringError::Mismatch(s.to_string()));
+    }
+    Ok(game)
+}
+
+// Plays each move in turn, passes are written out so they're only played
+// when they appear
+pub fn replay(rules: RuleSet, move_strings: &[&str]) -> Result<Game, GameStringError> {
+    let mut game = Game::new(rules);
+    game.set_auto_pass(false);
+    for move_string in move_strings {
+        let m = parse_move(game.board(), move_string).map_err(GameStringError::Notation)?;
+        game.play(m)
+            .map_err(|error| GameStringError::IllegalMove(move_string.to_string(), error))?;
+    }
+    game.set_auto_pass(true);
+    Ok(game)
+}
+
+pub fn game_state(game: &Game) -> String {
+    match game.state() {
+        GameState::InProgress if game.board().history().is_empty() => "NotStarted",
+        GameState::InProgress => "InProgress",
+        GameState::Draw => "Draw",
+        GameState::Won(PlayerNumber::One) => "WhiteWins",
+        GameState::Won(PlayerNumber::Two) => "BlackWins",
+    }
+    .to_string()
+}
+
+// The side to move and how many turns they've started, e.g. `Black[3]`
+fn turn(board: &Board) -> String {
+    let colour = match board.current_player() {
+        PlayerNumber::One => "White",
+        PlayerNumber::Two => "Black",
+    };
+    format!("{}[{}]", colour, board.turn() / 2 + 1)
+}
+
+fn is_turn(s: &str) -> bool {
+    let number = s
+        .strip_prefix("White[")
+        .or_else(|| s.strip_prefix("Black["))
+        .and_then(|rest| rest.strip_suffix(']'));
+    match number.map(|number| number.parse::<usize>()) {
+        Some(Ok(n)) => n > 0,
+        _ => false,
+    }
+}
+
+// Moves are named relative to the position they were played from so the
+// history is replayed from the start
+pub fn move_strings(board: &Board) -> Vec<String> {
+    let mut replay = Board::with_rules(*board.rules());
+    board
+        .history()
+        .iter()
+        .map(|m| {
+            let s = format_move(&replay, m);
+            replay
+                .play(m.clone())
+                .expect("history contains an illegal move");
+            s
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod game_type {
+    use super::*;
+
+    #[test]
+    fn base() {
+        assert_eq!("Base", game_type(&RuleSet::default()));
+        assert_eq!(Some(RuleSet::default()), parse_game_type("Base"));
+    }
+
+    #[test]
+    fn expansions() {
+        let rules = RuleSet {
+            mosquito: true,
+            pillbug: true,
+            ..RuleSet::default()
+        };
+        assert_eq!("Base+MP", game_type(&rules));
+        assert_eq!(Some(rules), parse_game_type("Base+MP"));
+    }
+
+    #[test]
+    fn invalid() {
+        for s in ["", "Base+", "Base+X", "Base+MM", "Extended", "MLP"] {
+            assert_eq!(None, parse_game_type(s));
+        }
+    }
+}
+
+#[cfg(test)]
+mod game_string {
+    use super::*;
+    use axial::Axial;
+    use moves::Move;
+    use tile::{ant, queen};
+
+    #[test]
+    fn not_started() {
+        let g = Game::new(RuleSet::default());
+        assert_eq!("Base;NotStarted;White[1]", game_string(&g));
+    }
+
+    #[test]
+    fn in_progress() {
+        let mut g = Game::new(RuleSet::default());
+        g.play(Move::Place(queen(), Axial::zero())).unwrap();
+        g.play(Move::Place(ant(), Axial::zero().south())).unwrap();
+        g.play(Move::Place(ant(), Axial::zero().north())).unwrap();
+        assert_eq!(
+            "Base;InProgress;Black[2];wQ;bA1 /wQ;wA1 wQ/",
+            game_string(&g)
+        );
+    }
+}
+
+#[cfg(test)]
+mod parse_game_string {
+    use super::*;
+    use axial::Axial;
+    use moves::Move;
+    use tile::{ant, pillbug, queen, TileKind};
+
+    #[test]
+    fn round_trip() {
+        let mut g = Game::new(RuleSet {
+            pillbug: true,
+            ..RuleSet::default()
+        });
+        let moves = vec![
+            Move::Place(pillbug(), Axial::zero()),
+            Move::Place(queen(), Axial::zero().south()),
+            Move::Place(queen(), Axial::zero().north()),
+            Move::Place(ant(), Axial::new(0, 2)),
+            Move::Place(ant(), Axial::zero().north_west()),
+            Move::Move(Axial::new(0, 2), Axial::zero().south_east()),
+            Move::Throw(Axial::zero().south(), Axial::zero().south_west()),
+        ];
+        for m in moves {
+            g.play(m).unwrap();
+        }
+
+        let s = game_string(&g);
+        assert_eq!(true, s.ends_with(";bQ /wA1"));
+        let parsed = parse_game_string(&s).unwrap();
+        assert_eq!(s, game_string(&parsed));
+        assert_eq!(g.board().position_key(), parsed.board().position_key());
+        for player in [PlayerNumber::One, PlayerNumber::Two] {
+            for kind in [TileKind::Queen, TileKind::Ant, TileKind::Pillbug] {
+                assert_eq!(
+                    g.player(player).reserve_count(kind),
+                    parsed.player(player).reserve_count(kind)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn not_started() {
+        let g = parse_game_string("Base+M;NotStarted;White[1]").unwrap();
+        assert_eq!(true, g.board().rules().mosquito);
+        assert_eq!(0, g.board().history().len());
+    }
+
+    #[test]
+    fn invalid_header() {
+        assert_eq!(
+            Err(GameStringError::InvalidGameType("Base+X".to_string())),
+            parse_game_string("Base+X;NotStarted;White[1]").map(|g| game_string(&g))
+        );
+        assert_eq!(
+            Err(GameStringError::InvalidGameState("Started".to_string())),
+            parse_game_string("Base;Started;White[1]").map(|g| game_string(&g))
+        );
+        assert_eq!(
+            Err(GameStringError::InvalidTurn("Red[1]".to_string())),
+            parse_game_string("Base;NotStarted;Red[1]").map(|g| game_string(&g))
+        );
+        assert_eq!(
+            Err(GameStringError::InvalidTurn("".to_string())),
+            parse_game_string("Base;NotStarted").map(|g| game_string(&g))
+        );
+    }
+
+    #[test]
+    fn illegal_move() {
+        assert_eq!(
+            Err(GameStringError::IllegalMove(
+                "bQ wQ".to_string(),
+                MoveError::OccupiedHex
+            )),
+            parse_game_string("Base;InProgress;White[2];wQ;bQ wQ").map(|g| game_string(&g))
+        );
+    }
+
+    #[test]
+    fn mismatch() {
+        let s = "Base;InProgress;White[1];wQ";
+        assert_eq!(
+            Err(GameStringError::Mismatch(s.to_string())),
+            parse_game_string(s).map(|g| game_string(&g))
+        );
+    }
+}
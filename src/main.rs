@@ -8,20 +8,27 @@ mod bounds;
 mod character_buffer_2d;
 mod coord_utils;
 mod game;
+mod game_record;
+mod game_string;
+mod move_error;
+mod moves;
+mod notation;
+mod piece_id;
 mod placed_tile;
 mod player;
 mod position;
 mod render;
+mod rule_set;
+mod sgf;
 mod tile;
+mod uhp;
 
-use game::Game;
-use player::*;
-use tile::standard_game_tiles;
+use std::io;
+use uhp::Engine;
 
 fn main() {
-    let mut g = Game::new(
-        Player::new(standard_game_tiles(), PlayerNumber::One),
-        Player::new(standard_game_tiles(), PlayerNumber::Two),
-    );
-    g.go();
+    let stdin = io::stdin();
+    Engine::new()
+        .run(stdin.lock(), io::stdout())
+        .expect("lost contact over stdin or stdout");
 }
@@ -0,0 +1,39 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum MoveError {
+    GameOver,
+    NotYourTurn,
+    NotInReserve,
+    OccupiedHex,
+    TouchesOpponent,
+    NotTouchingHive,
+    QueenMustBePlaced,
+    TournamentOpening,
+    QueenNotPlaced,
+    NoTileToMove,
+    BreaksHive,
+    IllegalMove,
+    CannotPass,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match self {
+            MoveError::GameOver => "the game is over",
+            MoveError::NotYourTurn => "it is not that player's turn",
+            MoveError::NotInReserve => "that tile is not in the player's reserve",
+            MoveError::OccupiedHex => "that position is already occupied",
+            MoveError::TouchesOpponent => "a placed tile cannot touch an opponent's tile",
+            MoveError::NotTouchingHive => "a placed tile must touch the hive",
+            MoveError::QueenMustBePlaced => "the queen must be placed this turn",
+            MoveError::TournamentOpening => "the queen cannot be placed on the first turn",
+            MoveError::QueenNotPlaced => "the queen must be placed before moving",
+            MoveError::NoTileToMove => "there is no tile at that position",
+            MoveError::BreaksHive => "moving that tile would split the hive",
+            MoveError::IllegalMove => "that tile cannot move there",
+            MoveError::CannotPass => "passing is only allowed with no other moves",
+        };
+        write!(f, "{}", reason)
+    }
+}
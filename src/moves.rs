@@ -0,0 +1,42 @@
+use axial::Axial;
+use std::fmt;
+use std::rc::Rc;
+use tile::Tile;
+
+#[derive(Clone)]
+pub enum Move {
+    Place(Rc<Tile>, Axial),
+    Move(Axial, Axial),
+    // A tile lifted from and to positions next to a pillbug
+    Throw(Axial, Axial),
+    Pass,
+}
+
+impl PartialEq for Move {
+    fn eq(&self, other: &Move) -> bool {
+        match (self, other) {
+            (Move::Place(tile, position), Move::Place(other_tile, other_position)) => {
+                tile.kind() == other_tile.kind() && position == other_position
+            }
+            (Move::Move(from, to), Move::Move(other_from, other_to)) => {
+                from == other_from && to == other_to
+            }
+            (Move::Throw(from, to), Move::Throw(other_from, other_to)) => {
+                from == other_from && to == other_to
+            }
+            (Move::Pass, Move::Pass) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Move::Place(tile, position) => write!(f, "Place({:?}, {})", tile.kind(), position),
+            Move::Move(from, to) => write!(f, "Move({}, {})", from, to),
+            Move::Throw(from, to) => write!(f, "Throw({}, {})", from, to),
+            Move::Pass => write!(f, "Pass"),
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use axial::Axial;
+use board::Board;
+use moves::Move;
+use piece_id::PieceId;
+use std::fmt;
+use tile;
+
+// Moves are written as the moving piece followed by a neighbouring reference
+// piece, e.g. `wA1 -bQ`. A symbol before or after the reference says which
+// side of it the piece ends up on and no symbol means on top of it. The
+// notation assumes pointy topped hexes so its east is our north east, and so
+// on round the clock
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum NotationError {
+    Malformed(String),
+    UnknownPiece(PieceId),
+    ReferenceNotInPlay(PieceId),
+    PieceCovered(PieceId),
+}
+
+impl fmt::Display for NotationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NotationError::Malformed(s) => write!(f, "'{}' is not a valid move", s),
+            NotationError::UnknownPiece(id) => {
+                write!(f, "{} is not in play or next out of the reserve", id)
+            }
+            NotationError::ReferenceNotInPlay(id) => write!(f, "{} is not in play", id),
+            NotationError::PieceCovered(id) => write!(f, "{} is covered by another piece", id),
+        }
+    }
+}
+
+// Whether the symbol goes before the reference, the symbol and the direction
+// from the reference to the piece
+type Direction = (bool, char, fn(&Axial) -> Axial);
+
+fn directions() -> Vec<Direction> {
+    vec![
+        (false, '-', Axial::north_east),
+        (false, '\\', Axial::south_east),
+        (true, '/', Axial::south),
+        (true, '-', Axial::south_west),
+        (true, '\\', Axial::north_west),
+        (false, '/', Axial::north),
+    ]
+}
+
+// `board` is the position the move is played from
+pub fn parse_move(board: &Board, s: &str) -> Result<Move, NotationError> {
+    let malformed = || NotationError::Malformed(s.to_string());
+    let parts: Vec<&str> = s.split_whitespace().collect();
+    if parts == vec!["pass"] {
+        return Ok(Move::Pass);
+    }
+
+    let id = match parts.first() {
+        Some(piece) => PieceId::parse(piece).ok_or_else(malformed)?,
+        None => return Err(malformed()),
+    };
+    let to = match parts.len() {
+        1 if board.is_empty() => Axial::zero(),
+        2 => parse_destination(board, parts[1]).ok_or_else(malformed)??,
+        _ => return Err(malformed()),
+    };
+
+    match board.piece_position(id) {
+        Some(from) if board.top_tile(&from).map(|placed_tile| placed_tile.id) != Some(id) => {
+            Err(NotationError::PieceCovered(id))
+        }
+        Some(from) => {
+            // Only a throw can move an opponent's tile
+            let can_move =
+                id.player == board.current_player() && board.possible_moves(from).contains(&to);
+            if !can_move && board.can_be_thrown(from, to) {
+                Ok(Move::Throw(from, to))
+            } else {
+                Ok(Move::Move(from, to))
+            }
+        }
+        None if id == board.next_piece_id(board.current_player(), id.kind) => {
+            Ok(Move::Place(tile::from_kind(id.kind), to))
+        }
+        None => Err(NotationError::UnknownPiece(id)),
+    }
+}
+
+// None if `reference` isn't well formed
+fn parse_destination(board: &Board, reference: &str) -> Option<Result<Axial, NotationError>> {
+    let is_symbol = |c: char| c == '-' || c == '/' || c == '\\';
+    let first = reference.chars().next()?;
+    let last = reference.chars().last()?;
+    let (symbol, name) = match (is_symbol(first), is_symbol(last)) {
+        (true, false) => (Some((true, first)), &reference[1..]),
+        (false, true) => (Some((false, last)), &reference[..reference.len() - 1]),
+        (false, false) => (None, reference),
+        (true, true) => return None,
+    };
+
+    let id = PieceId::parse(name)?;
+    let position = match board.piece_position(id) {
+        Some(position) => position,
+        None => return Some(Err(NotationError::ReferenceNotInPlay(id))),
+    };
+    Some(Ok(match symbol {
+        Some(symbol) => directions()
+            .into_iter()
+            .find(|(before, c, _)| (*before, *c) == symbol)
+            .map(|(_, _, direction)| direction(&position))
+            .unwrap(),
+        None => position,
+    }))
+}
+
+// `board` is the position `m` is played from and `m` must be legal there
+pub fn format_move(board: &Board, m: &Move) -> String {
+    match m {
+        Move::Pass => "pass".to_string(),
+        Move::Place(tile, to) => {
+            let id = board.next_piece_id(board.current_player(), tile.kind());
+            format_destination(board, id, *to)
+        }
+        Move::Move(from, to) | Move::Throw(from, to) => {
+            let id = board.top_tile(from).unwrap().id;
+            format_destination(&board.without_tile_at(*from), id, *to)
+        }
+    }
+}
+
+fn format_destination(board: &Board, id: PieceId, to: Axial) -> String {
+    if let Some(placed_tile) = board.top_tile(&to) {
+        return format!("{} {}", id, placed_tile.id);
+    }
+
+    for position in to.surrounding_positions() {
+        let reference = match board.top_tile(&position) {
+            Some(placed_tile) => placed_tile.id,
+            None => continue,
+        };
+        for (before, symbol, direction) in directions() {
+            if direction(&position) != to {
+                continue;
+            }
+            return if before {
+                format!("{} {}{}", id, symbol, reference)
+            } else {
+                format!("{} {}{}", id, reference, symbol)
+            };
+        }
+    }
+
+    // Only the first tile is placed with nothing to refer to
+    id.to_string()
+}
+
+#[cfg(test)]
+mod parse_move {
+    use super::*;
+    use board::helpers::*;
+    use player::PlayerNumber;
+    use tile::{ant, beetle, pillbug, queen, TileKind};
+
+    #[test]
+    fn first_placement() {
+        let b = Board::new();
+        assert_eq!(Ok(Move::Place(ant(), Axial::zero())), parse_move(&b, "wA1"));
+    }
+
+    #[test]
+    fn placement_beside_reference() {
+        let mut b = Board::new();
+        b.place_tile(queen(), Axial::zero(), PlayerNumber::One)
+            .unwrap();
+        let expected = vec![
+            ("bA1 wQ-", Axial::zero().north_east()),
+            ("bA1 wQ\\", Axial::zero().south_east()),
+            ("bA1 /wQ", Axial::zero().south()),
+            ("bA1 -wQ", Axial::zero().south_west()),
+            ("bA1 \\wQ", Axial::zero().north_west()),
+            ("bA1 wQ/", Axial::zero().north()),
+        ];
+        for (s, position) in expected {
+            assert_eq!(Ok(Move::Place(ant(), position)), parse_move(&b, s));
+        }
+    }
+
+    #[test]
+    fn climb_onto_reference() {
+        let b = board_with_players(vec![
+            (queen(), Axial::zero(), PlayerNumber::One),
+            (beetle(), Axial::zero().south(), PlayerNumber::One),
+            (queen(), Axial::zero().north(), PlayerNumber::Two),
+        ]);
+        assert_eq!(
+            Ok(Move::Move(Axial::zero().south(), Axial::zero())),
+            parse_move(&b, "wB1 wQ")
+        );
+    }
+
+    #[test]
+    fn covered_piece() {
+        let b = board_with_players(vec![
+            (queen(), Axial::zero(), PlayerNumber::One),
+            (beetle(), Axial::zero(), PlayerNumber::One),
+            (queen(), Axial::zero().north(), PlayerNumber::Two),
+        ]);
+        assert_eq!(
+            Err(NotationError::PieceCovered(PieceId::new(
+                PlayerNumber::One,
+                TileKind::Queen,
+                1
+            ))),
+            parse_move(&b, "wQ \\bQ")
+        );
+    }
+
+    #[test]
+    fn throw() {
+        let b = board_with_players(vec![
+            (pillbug(), Axial::zero(), PlayerNumber::One),
+            (queen(), Axial::zero().north(), PlayerNumber::One),
+            (ant(), Axial::zero().south(), PlayerNumber::Two),
+            (queen(), Axial::zero().south_east(), PlayerNumber::Two),
+        ]);
+        assert_eq!(
+            Ok(Move::Throw(
+                Axial::zero().south(),
+                Axial::zero().north_west()
+            )),
+            parse_move(&b, "bA1 \\wP")
+        );
+    }
+
+    #[test]
+    fn pass() {
+        assert_eq!(Ok(Move::Pass), parse_move(&Board::new(), "pass"));
+    }
+
+    #[test]
+    fn unknown_piece() {
+        let b = Board::new();
+        assert_eq!(
+            Err(NotationError::UnknownPiece(PieceId::new(
+                PlayerNumber::One,
+                TileKind::Ant,
+                2
+            ))),
+            parse_move(&b, "wA2")
+        );
+    }
+
+    #[test]
+    fn reference_not_in_play() {
+        let b = board_with(vec![(queen(), Axial::zero())]);
+        assert_eq!(
+            Err(NotationError::ReferenceNotInPlay(PieceId::new(
+                PlayerNumber::Two,
+                TileKind::Queen,
+                1
+            ))),
+            parse_move(&b, "wA1 bQ-")
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let b = board_with(vec![(queen(), Axial::zero())]);
+        for s in vec!["", "wA1", "wA1 -wQ-", "wA1 wQ wQ", "wX1 -wQ", "wA1 -"] {
+            assert_eq!(
+                Err(NotationError::Malformed(s.to_string())),
+                parse_move(&b, s)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod format_move {
+    use super::*;
+    use board::helpers::*;
+    use player::PlayerNumber;
+    use tile::{ant, beetle, queen};
+
+    #[test]
+    fn first_placement() {
+        assert_eq!(
+            "wQ",
+            format_move(&Board::new(), &Move::Place(queen(), Axial::zero()))
+        );
+    }
+
+    #[test]
+    fn placement() {
+        let mut b = Board::new();
+        b.place_tile(queen(), Axial::zero(), PlayerNumber::One)
+            .unwrap();
+        assert_eq!(
+            "bA1 /wQ",
+            format_move(&b, &Move::Place(ant(), Axial::zero().south()))
+        );
+    }
+
+    #[test]
+    fn move_does_not_refer_to_itself() {
+        let b = board_with_players(vec![
+            (queen(), Axial::zero(), PlayerNumber::One),
+            (ant(), Axial::zero().north(), PlayerNumber::One),
+            (queen(), Axial::zero().south(), PlayerNumber::Two),
+        ]);
+        assert_eq!(
+            "wA1 wQ-",
+            format_move(
+                &b,
+                &Move::Move(Axial::zero().north(), Axial::zero().north_east())
+            )
+        );
+    }
+
+    #[test]
+    fn climb() {
+        let b = board_with_players(vec![
+            (queen(), Axial::zero(), PlayerNumber::One),
+            (beetle(), Axial::zero().south(), PlayerNumber::One),
+        ]);
+        assert_eq!(
+            "wB1 wQ",
+            format_move(&b, &Move::Move(Axial::zero().south(), Axial::zero()))
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let b = board_with_players(vec![
+            (queen(), Axial::zero(), PlayerNumber::One),
+            (ant(), Axial::zero().north(), PlayerNumber::One),
+            (queen(), Axial::zero().south(), PlayerNumber::Two),
+            (ant(), Axial::zero().south().south(), PlayerNumber::Two),
+        ]);
+        for m in b.legal_moves(PlayerNumber::One, &[beetle()]) {
+            assert_eq!(Ok(m.clone()), parse_move(&b, &format_move(&b, &m)));
+        }
+    }
+}
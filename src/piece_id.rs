@@ -0,0 +1,111 @@
+use player::PlayerNumber;
+use std::fmt;
+use tile::TileKind;
+
+// Names an individual piece, ordinals count from 1 in the order pieces of
+// the same kind leave the player's reserve
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash, PartialOrd, Ord)]
+pub struct PieceId {
+    pub player: PlayerNumber,
+    pub kind: TileKind,
+    pub ordinal: usize,
+}
+
+impl PieceId {
+    pub fn new(player: PlayerNumber, kind: TileKind, ordinal: usize) -> PieceId {
+        PieceId {
+            player,
+            kind,
+            ordinal,
+        }
+    }
+
+    // The inverse of `Display`, unique pieces must not be numbered
+    pub fn parse(s: &str) -> Option<PieceId> {
+        let mut chars = s.chars();
+        let player = match chars.next()? {
+            'w' => PlayerNumber::One,
+            'b' => PlayerNumber::Two,
+            _ => return None,
+        };
+        let kind = TileKind::from_letter(chars.next()?)?;
+        let ordinal = chars.as_str();
+        if kind.is_unique() {
+            return if ordinal.is_empty() {
+                Some(PieceId::new(player, kind, 1))
+            } else {
+                None
+            };
+        }
+        match ordinal.parse() {
+            Ok(ordinal) if ordinal > 0 => Some(PieceId::new(player, kind, ordinal)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for PieceId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let colour = match self.player {
+            PlayerNumber::One => 'w',
+            PlayerNumber::Two => 'b',
+        };
+        write!(f, "{}{}", colour, self.kind.letter())?;
+        if !self.kind.is_unique() {
+            write!(f, "{}", self.ordinal)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod parse {
+    use super::*;
+
+    #[test]
+    fn numbered() {
+        assert_eq!(
+            Some(PieceId::new(PlayerNumber::Two, TileKind::Ant, 2)),
+            PieceId::parse("bA2")
+        );
+    }
+
+    #[test]
+    fn unique() {
+        assert_eq!(
+            Some(PieceId::new(PlayerNumber::One, TileKind::Queen, 1)),
+            PieceId::parse("wQ")
+        );
+        assert_eq!(None, PieceId::parse("wQ1"));
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(None, PieceId::parse("wA"));
+        assert_eq!(None, PieceId::parse("wA0"));
+        assert_eq!(None, PieceId::parse("xA1"));
+        assert_eq!(None, PieceId::parse("wX1"));
+        assert_eq!(None, PieceId::parse(""));
+    }
+}
+
+#[cfg(test)]
+mod display {
+    use super::*;
+
+    #[test]
+    fn numbered() {
+        assert_eq!(
+            "bA2",
+            PieceId::new(PlayerNumber::Two, TileKind::Ant, 2).to_string()
+        );
+    }
+
+    #[test]
+    fn unique() {
+        assert_eq!(
+            "wQ",
+            PieceId::new(PlayerNumber::One, TileKind::Queen, 1).to_string()
+        );
+    }
+}
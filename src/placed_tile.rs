@@ -1,41 +1,48 @@
 use std::rc::Rc;
 use axial::Axial;
-use player::PlayerNumber;
+use piece_id::PieceId;
 use tile::Tile;
 use std::cmp::Ordering;
 use std::cmp::Eq;
 
 pub struct PlacedTile {
     pub tile: Rc<Tile>,
-    pub player: PlayerNumber,
+    pub id: PieceId,
     pub position: Axial,
+    pub last_moved: Option<usize>,
+    pub thrown: bool,
 }
 
 impl PlacedTile {
-    pub fn new(tile: Rc<Tile>, position: Axial, player: PlayerNumber) -> PlacedTile {
+    pub fn new(tile: Rc<Tile>, position: Axial, id: PieceId) -> PlacedTile {
         PlacedTile {
             tile: tile,
-            player: player,
+            id: id,
             position: position,
+            last_moved: None,
+            thrown: false,
         }
     }
 }
 
+// Tiles on the same stack are told apart by their piece
 impl Ord for PlacedTile {
     fn cmp(&self, other: &PlacedTile) -> Ordering {
-        self.position.cmp(&other.position)
+        self.position
+            .cmp(&other.position)
+            .then(self.id.cmp(&other.id))
     }
 }
 
 impl PartialOrd for PlacedTile {
     fn partial_cmp(&self, other: &PlacedTile) -> Option<Ordering> {
-        self.position.partial_cmp(&other.position)
+        Some(self.cmp(other))
     }
 }
 
 impl PartialEq for PlacedTile {
     fn eq(&self, other: &PlacedTile) -> bool {
-        self.position == other.position
+        self.position == other.position && self.id == other.id
     }
 }
 
@@ -1,9 +1,11 @@
 use std::rc::Rc;
 use tile::Tile;
+use tile::TileKind;
 use board::Board;
 use axial::Axial;
+use moves::Move;
 
-#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash, PartialOrd, Ord)]
 pub enum PlayerNumber {
     One,
     Two,
@@ -20,15 +22,101 @@ impl PlayerNumber {
 
 pub struct Player {
     tiles: Vec<Rc<Tile>>,
+    in_reserve: Vec<bool>,
     number: PlayerNumber,
 }
 
 impl Player {
     pub fn new(tiles: Vec<Rc<Tile>>, number: PlayerNumber) -> Player {
-        return Player { tiles, number };
+        let in_reserve = vec![true; tiles.len()];
+        return Player {
+            tiles,
+            in_reserve,
+            number,
+        };
     }
 
-    pub fn get_tile_placement(&self, board: &Board) -> (Rc<Tile>, Axial) {
-        (self.tiles[0].clone(), board.get_possible_tile_placements(self.number)[0])
+    pub fn number(&self) -> PlayerNumber {
+        self.number
+    }
+
+    pub fn reserve(&self) -> Vec<Rc<Tile>> {
+        self.tiles
+            .iter()
+            .zip(self.in_reserve.iter())
+            .filter(|(_, in_reserve)| **in_reserve)
+            .map(|(tile, _)| tile.clone())
+            .collect()
+    }
+
+    pub fn reserve_count(&self, kind: TileKind) -> usize {
+        self.tiles
+            .iter()
+            .zip(&self.in_reserve)
+            .filter(|(tile, in_reserve)| **in_reserve && tile.kind() == kind)
+            .count()
+    }
+
+    // Tiles of a kind are taken in reserve order and returned in reverse, so
+    // taking and returning a tile leaves the reserve exactly as it was
+    pub fn take_tile(&mut self, kind: TileKind) -> Option<Rc<Tile>> {
+        let index = (0..self.tiles.len())
+            .find(|index| self.in_reserve[*index] && self.tiles[*index].kind() == kind)?;
+        self.in_reserve[index] = false;
+        Some(self.tiles[index].clone())
+    }
+
+    pub fn return_tile(&mut self, kind: TileKind) {
+        let index = (0..self.tiles.len())
+            .rev()
+            .find(|index| !self.in_reserve[*index] && self.tiles[*index].kind() == kind)
+            .expect("tile was never taken from reserve");
+        self.in_reserve[index] = true;
+    }
+
+    pub fn get_move(&self, board: &Board) -> Move {
+        board.legal_moves(self.number, &self.reserve()).remove(0)
+    }
+}
+
+#[cfg(test)]
+mod reserve {
+    use super::*;
+    use tile::standard_game_tiles;
+
+    #[test]
+    fn standard_game() {
+        let p = Player::new(standard_game_tiles(), PlayerNumber::One);
+        assert_eq!(1, p.reserve_count(TileKind::Queen));
+        assert_eq!(3, p.reserve_count(TileKind::Ant));
+        assert_eq!(11, p.reserve().len());
+    }
+
+    #[test]
+    fn take_tile() {
+        let mut p = Player::new(standard_game_tiles(), PlayerNumber::One);
+        assert_eq!(TileKind::Ant, p.take_tile(TileKind::Ant).unwrap().kind());
+        assert_eq!(2, p.reserve_count(TileKind::Ant));
+        assert_eq!(10, p.reserve().len());
+    }
+
+    #[test]
+    fn take_last_tile() {
+        let mut p = Player::new(standard_game_tiles(), PlayerNumber::One);
+        assert_eq!(true, p.take_tile(TileKind::Queen).is_some());
+        assert_eq!(true, p.take_tile(TileKind::Queen).is_none());
+        assert_eq!(0, p.reserve_count(TileKind::Queen));
+    }
+
+    #[test]
+    fn return_tile() {
+        let mut p = Player::new(standard_game_tiles(), PlayerNumber::One);
+        p.take_tile(TileKind::Queen);
+        p.take_tile(TileKind::Ant);
+        p.return_tile(TileKind::Queen);
+        p.return_tile(TileKind::Ant);
+
+        let kinds = |tiles: Vec<Rc<Tile>>| tiles.iter().map(|tile| tile.kind()).collect::<Vec<_>>();
+        assert_eq!(kinds(standard_game_tiles()), kinds(p.reserve()));
     }
 }
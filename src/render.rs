@@ -2,6 +2,7 @@ use axial::Axial;
 use bounds::Bounds;
 use character_buffer_2d::CharacterBuffer2D;
 use coord_utils;
+use piece_id::PieceId;
 use placed_tile::PlacedTile;
 use player::PlayerNumber;
 use std::cmp;
@@ -59,7 +60,22 @@ impl RenderStdout {
         }
     }
 
+    pub fn remove(&mut self, tile: &Rc<PlacedTile>) {
+        self.pieces.retain(|piece| !Rc::ptr_eq(piece, tile));
+        let positions: Vec<Axial> = self.pieces.iter().map(|piece| piece.position).collect();
+        self.bounds = None;
+        for position in positions {
+            self.update_bounds(position);
+        }
+    }
+
     pub fn render(&self) {
+        if self.bounds.is_some() {
+            println!("{}", self.render_to_string());
+        }
+    }
+
+    pub fn render_to_string(&self) -> String {
         macro_rules! get_char {
             ($x:expr, $y:expr) => {
                 unsafe {
@@ -74,7 +90,7 @@ impl RenderStdout {
         }
 
         match self.bounds {
-            None => {}
+            None => String::new(),
             Some(bounds) => {
                 let height = self.characters_high();
                 let width = self.characters_wide();
@@ -93,7 +109,7 @@ impl RenderStdout {
                         }
                     }
                 }
-                println!("{}", buffer.to_string());
+                buffer.to_string()
             }
         }
     }
@@ -165,8 +181,9 @@ mod helpers {
 
     pub fn render_with_tiles_at_positions(positions: Vec<Axial>) -> RenderStdout {
         let mut r = RenderStdout::new();
-        for position in positions {
-            r.push(Rc::new(PlacedTile::new(ant(), position, PlayerNumber::One)));
+        for (index, position) in positions.into_iter().enumerate() {
+            let id = PieceId::new(PlayerNumber::One, tile::TileKind::Ant, index + 1);
+            r.push(Rc::new(PlacedTile::new(ant(), position, id)));
         }
         r
     }
@@ -180,7 +197,7 @@ mod render {
     #[test]
     fn empty_board() {
         let r = render_with_tiles_at_positions(vec![]);
-        assert_eq!("", r.render());
+        assert_eq!("", r.render_to_string());
     }
 
     #[test]
@@ -190,14 +207,14 @@ mod render {
             format!(
                 "{}\n",
                 [
-                    r#" \____/ "#,
+                    r#"  ____  "#,
                     r#" /    \ "#,
-                    r#"/      \"#,
+                    r#"/  A   \"#,
                     r#"\      /"#,
-                    r#" \____/ "#
+                    r#" \____/ "#,
                 ].join("\n")
             ),
-            r.render()
+            r.render_to_string()
         );
     }
 
@@ -208,14 +225,14 @@ mod render {
             format!(
                 "{}\n",
                 [
-                    r#" \____/ "#,
+                    r#"  ____  "#,
                     r#" /    \ "#,
-                    r#"/      \"#,
+                    r#"/  A   \"#,
                     r#"\      /"#,
-                    r#" \____/ "#
+                    r#" \____/ "#,
                 ].join("\n")
             ),
-            r.render()
+            r.render_to_string()
         );
     }
 
@@ -226,18 +243,18 @@ mod render {
             format!(
                 "{}\n",
                 [
-                    r#" \____/ "#,
+                    r#"  ____  "#,
                     r#" /    \ "#,
-                    r#"/      \"#,
+                    r#"/  A   \"#,
                     r#"\      /"#,
                     r#" \____/ "#,
                     r#" /    \ "#,
-                    r#"/      \"#,
+                    r#"/  A   \"#,
                     r#"\      /"#,
-                    r#" \____/ "#
+                    r#" \____/ "#,
                 ].join("\n")
             ),
-            r.render()
+            r.render_to_string()
         );
     }
 
@@ -252,22 +269,22 @@ mod render {
             format!(
                 "{}\n",
                 [
-                    r#" \____/ "#,
+                    r#"  ____  "#,
                     r#" /    \ "#,
-                    r#"/      \"#,
+                    r#"/  A   \"#,
                     r#"\      /"#,
                     r#" \____/ "#,
                     r#" /    \ "#,
-                    r#"/      \"#,
+                    r#"/  A   \"#,
                     r#"\      /"#,
                     r#" \____/ "#,
                     r#" /    \ "#,
-                    r#"/      \"#,
+                    r#"/  A   \"#,
                     r#"\      /"#,
-                    r#" \____/ "#
+                    r#" \____/ "#,
                 ].join("\n")
             ),
-            r.render()
+            r.render_to_string()
         );
     }
 
@@ -278,16 +295,16 @@ mod render {
             format!(
                 "{}\n",
                 [
-                    r#" \____/       "#,
+                    r#"  ____        "#,
                     r#" /    \       "#,
-                    r#"/      \____/ "#,
+                    r#"/  A   \____  "#,
                     r#"\      /    \ "#,
-                    r#" \____/      \"#,
+                    r#" \____/  A   \"#,
                     r#"      \      /"#,
                     r#"       \____/ "#,
                 ].join("\n")
             ),
-            r.render()
+            r.render_to_string()
         );
     }
 
@@ -298,16 +315,16 @@ mod render {
             format!(
                 "{}\n",
                 [
-                    r#"       \____/ "#,
+                    r#"        ____  "#,
                     r#"       /    \ "#,
-                    r#" \____/      \"#,
+                    r#"  ____/  A   \"#,
                     r#" /    \      /"#,
-                    r#"/      \____/ "#,
+                    r#"/  A   \____/ "#,
                     r#"\      /      "#,
                     r#" \____/       "#,
                 ].join("\n")
             ),
-            r.render()
+            r.render_to_string()
         );
     }
 }
@@ -322,10 +339,10 @@ mod render_position {
         let r = render_with_tiles_at_positions(vec![Axial::zero(), Axial::zero().south_west()]);
         assert_eq!(
             (0, TEMPLATE_HEIGHT_HORIZ_ADD),
-            r.render_position(Axial::zero().south_west())
+            r.render_position(&Axial::zero().south_west())
         );
 
-        assert_eq!((TEMPLATE_WIDTH_ADD, 0), r.render_position(Axial::zero()));
+        assert_eq!((TEMPLATE_WIDTH_ADD, 0), r.render_position(&Axial::zero()));
     }
 }
 
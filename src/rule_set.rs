@@ -0,0 +1,76 @@
+use std::rc::Rc;
+use tile::*;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct RuleSet {
+    pub mosquito: bool,
+    pub ladybug: bool,
+    pub pillbug: bool,
+    // Neither player may place their queen on their first turn
+    pub tournament_opening: bool,
+    // The player's turn, counting from 1, by which their queen must be placed
+    pub queen_deadline: usize,
+    // The game is drawn once this many turns have been taken in total
+    pub move_limit: Option<usize>,
+}
+
+impl RuleSet {
+    pub fn tiles(&self) -> Vec<Rc<Tile>> {
+        let mut tiles = standard_game_tiles();
+        if self.mosquito {
+            tiles.push(mosquito());
+        }
+        if self.ladybug {
+            tiles.push(ladybug());
+        }
+        if self.pillbug {
+            tiles.push(pillbug());
+        }
+        tiles
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> RuleSet {
+        RuleSet {
+            mosquito: false,
+            ladybug: false,
+            pillbug: false,
+            tournament_opening: false,
+            queen_deadline: 4,
+            move_limit: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tiles {
+    use super::*;
+
+    fn count(tiles: &[Rc<Tile>], kind: TileKind) -> usize {
+        tiles.iter().filter(|tile| tile.kind() == kind).count()
+    }
+
+    #[test]
+    fn standard() {
+        let tiles = RuleSet::default().tiles();
+        assert_eq!(11, tiles.len());
+        assert_eq!(0, count(&tiles, TileKind::Mosquito));
+        assert_eq!(0, count(&tiles, TileKind::Ladybug));
+        assert_eq!(0, count(&tiles, TileKind::Pillbug));
+    }
+
+    #[test]
+    fn expansions() {
+        let tiles = RuleSet {
+            mosquito: true,
+            pillbug: true,
+            ..RuleSet::default()
+        }
+        .tiles();
+        assert_eq!(13, tiles.len());
+        assert_eq!(1, count(&tiles, TileKind::Mosquito));
+        assert_eq!(0, count(&tiles, TileKind::Ladybug));
+        assert_eq!(1, count(&tiles, TileKind::Pillbug));
+    }
+}
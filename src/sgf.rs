@@ -0,0 +1,337 @@
+use game_record::GameRecord;
+use game_string::{replay, GameStringError};
+use player::PlayerNumber;
+use rule_set::RuleSet;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+// Reads the SGF game archives from boardspace.net. Each action records the
+// grid square a piece went to followed by the same reference notation we use,
+// only the notation is read. Picking up and setting down pieces, `done` and
+// draw offers don't change the position so they're skipped. A resignation
+// ends the game there
+#[derive(Debug)]
+pub enum SgfError {
+    Io(String),
+    Syntax(String),
+    NotHive(String),
+    UnknownAction(String),
+    MissingReference(String),
+    Replay(GameStringError),
+}
+
+impl fmt::Display for SgfError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SgfError::Io(error) => write!(f, "{}", error),
+            SgfError::Syntax(reason) => write!(f, "invalid SGF, {}", reason),
+            SgfError::NotHive(game) => write!(f, "'{}' is not a game of hive", game),
+            SgfError::UnknownAction(action) => write!(f, "'{}' is not a known action", action),
+            SgfError::MissingReference(action) => {
+                write!(f, "'{}' doesn't say where the piece went", action)
+            }
+            SgfError::Replay(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+pub fn load_sgf(path: &Path) -> Result<GameRecord, SgfError> {
+    let s = fs::read_to_string(path).map_err(|error| SgfError::Io(error.to_string()))?;
+    parse_sgf(&s)
+}
+
+pub fn parse_sgf(s: &str) -> Result<GameRecord, SgfError> {
+    let properties = parse_properties(s)?;
+    let property = |key: &str| {
+        properties
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    };
+
+    let variant = property("SU").unwrap_or("");
+    let rules = parse_variant(variant).ok_or_else(|| SgfError::NotHive(variant.to_string()))?;
+
+    let mut names = [String::new(), String::new()];
+    let mut moves = Vec::new();
+    let mut first_colour = None;
+    let mut resigned = None;
+    for (key, value) in &properties {
+        let player = match key.as_str() {
+            "P0" => 0,
+            "P1" => 1,
+            _ => continue,
+        };
+        if let Some(name) = value.strip_prefix("id ") {
+            names[player] = name.trim().trim_matches('"').to_string();
+            continue;
+        }
+
+        // Actions are numbered
+        let action = value
+            .trim_start_matches(|c: char| c.is_ascii_digit())
+            .trim();
+        let words: Vec<&str> = action.split_whitespace().collect();
+        let (piece, reference) = match words.first().map(|word| word.to_lowercase()) {
+            Some(ref name) if name == "dropb" || name == "pdropb" => (words.get(1), words.get(4)),
+            Some(ref name) if name == "move" || name == "pmove" => (words.get(2), words.get(5)),
+            Some(ref name) if name == "pass" => {
+                moves.push("pass".to_string());
+                continue;
+            }
+            Some(ref name) if name == "resign" => {
+                resigned = Some(player);
+                break;
+            }
+            Some(ref name) if is_skipped(name) => continue,
+            _ => return Err(SgfError::UnknownAction(action.to_string())),
+        };
+
+        let piece = piece.ok_or_else(|| SgfError::UnknownAction(action.to_string()))?;
+        if first_colour.is_none() {
+            first_colour = Some((player, piece.starts_with('w')));
+        }
+        moves.push(match reference {
+            Some(&".") if moves.is_empty() => piece.to_string(),
+            Some(reference) if *reference != "." => format!("{} {}", piece, reference),
+            _ => return Err(SgfError::MissingReference(action.to_string())),
+        });
+    }
+
+    let move_strings: Vec<&str> = moves.iter().map(|m| m.as_str()).collect();
+    let mut game = replay(rules, &move_strings).map_err(SgfError::Replay)?;
+
+    // Either player can go first
+    let swapped = first_colour == Some((0, false)) || first_colour == Some((1, true));
+    if swapped {
+        names.swap(0, 1);
+    }
+    if let Some(player) = resigned {
+        game.resign(if (player == 0) != swapped {
+            PlayerNumber::One
+        } else {
+            PlayerNumber::Two
+        });
+    }
+    Ok(GameRecord {
+        white: names[0].clone(),
+        black: names[1].clone(),
+        date: property("DT").unwrap_or("").to_string(),
+        game,
+    })
+}
+
+fn is_skipped(action: &str) -> bool {
+    [
+        "start",
+        "done",
+        "pick",
+        "pickb",
+        "drop",
+        "offerdraw",
+        "acceptdraw",
+        "declinedraw",
+    ]
+    .contains(&action)
+}
+
+// `hive` followed by the expansions in play, e.g. `hive-plm`
+fn parse_variant(s: &str) -> Option<RuleSet> {
+    let mut rules = RuleSet::default();
+    let expansions = if s == "hive" {
+        ""
+    } else {
+        s.strip_prefix("hive-")?
+    };
+    for letter in expansions.chars() {
+        match letter {
+            'm' => rules.mosquito = true,
+            'l' => rules.ladybug = true,
+            'p' => rules.pillbug = true,
+            _ => return None,
+        }
+    }
+    Some(rules)
+}
+
+// Every `KEY[value]` in the file in order, the tree structure of nodes and
+// variations isn't needed. Boardspace doesn't escape values, a `\` is part of
+// a reference like `\wQ`
+fn parse_properties(s: &str) -> Result<Vec<(String, String)>, SgfError> {
+    let s = s.trim();
+    if !s.starts_with('(') || !s.ends_with(')') {
+        return Err(SgfError::Syntax(
+            "the game should be wrapped in brackets".to_string(),
+        ));
+    }
+
+    let mut properties = Vec::new();
+    let mut key = String::new();
+    let mut after_value = false;
+    let mut chars = s.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '[' => {
+                if key.is_empty() {
+                    return Err(SgfError::Syntax(format!(
+                        "value without a property at character {}",
+                        index
+                    )));
+                }
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, ']')) => break,
+                        Some((_, c)) => value.push(c),
+                        None => {
+                            return Err(SgfError::Syntax(format!(
+                                "unterminated value at character {}",
+                                index
+                            )))
+                        }
+                    }
+                }
+                properties.push((key.clone(), value));
+                after_value = true;
+            }
+            c if c.is_ascii_alphanumeric() => {
+                // A property can have several values, `A[1][2]`
+                if after_value {
+                    key.clear();
+                    after_value = false;
+                }
+                key.push(c);
+            }
+            '(' | ')' | ';' => {
+                key.clear();
+                after_value = false;
+            }
+            c if c.is_whitespace() => {}
+            _ => {
+                return Err(SgfError::Syntax(format!(
+                    "unexpected '{}' at character {}",
+                    c, index
+                )))
+            }
+        }
+    }
+    Ok(properties)
+}
+
+#[cfg(test)]
+mod parse_sgf {
+    use super::*;
+    use game::GameState;
+    use game_string::game_string;
+    use move_error::MoveError;
+
+    static GAME: &str = "(;
+GM[27]VV[1]
+SU[hive-p]
+P0[id \"alice\"]
+P1[id \"bob\"]
+DT[2024-03-01]
+; P0[0 Start P0]
+; P0[1 dropb wP N 13 .]
+; P0[2 done]
+; P1[3 dropb bQ N 14 /wP]
+; P1[4 done]
+; P0[5 pdropb wQ N 12 wP/]
+; P0[6 done]
+; P1[7 pick B 1 bA1]
+; P1[8 dropb bA1 N 15 /bQ]
+; P1[9 done]
+; P0[10 dropb wA1 M 12 -wQ]
+; P0[11 done]
+; P1[12 pmove B bA1 O 14 bQ-]
+; P1[13 done]
+; P0[14 pickb N 14 bQ]
+; P0[15 dropb bQ M 13 /wA1]TM[12]
+; P0[16 done]
+; P1[17 dropb bG1 P 14 bA1\\]
+; P1[18 done]
+; P0[19 dropb wG1 L 12 \\wQ]
+; P0[20 done]
+)";
+
+    fn error(s: &str) -> String {
+        match parse_sgf(s) {
+            Err(error) => error.to_string(),
+            Ok(_) => panic!("expected an error"),
+        }
+    }
+
+    #[test]
+    fn game() {
+        let record = parse_sgf(GAME).unwrap();
+        assert_eq!("alice", record.white);
+        assert_eq!("bob", record.black);
+        assert_eq!("2024-03-01", record.date);
+        assert_eq!(
+            "Base+P;InProgress;Black[5];wP;bQ /wP;wQ wP/;bA1 /bQ;wA1 -wQ;bA1 bQ-;bQ /wA1;\
+             bG1 bA1\\;wG1 \\wQ",
+            game_string(&record.game)
+        );
+    }
+
+    #[test]
+    fn resign() {
+        let record = parse_sgf(&GAME.replace("\n)", "\n; P1[21 Resign]\n)")).unwrap();
+        assert_eq!(GameState::Won(PlayerNumber::One), record.game.state());
+    }
+
+    #[test]
+    fn black_first() {
+        let s = GAME
+            .replace("P0[", "PX[")
+            .replace("P1[", "P0[")
+            .replace("PX[", "P1[");
+        let record = parse_sgf(&s).unwrap();
+        assert_eq!("alice", record.white);
+        assert_eq!("bob", record.black);
+    }
+
+    #[test]
+    fn syntax() {
+        assert_eq!(
+            "invalid SGF, the game should be wrapped in brackets",
+            error("GM[27]")
+        );
+        assert_eq!(
+            "invalid SGF, unterminated value at character 12",
+            error("(;SU[hive]P0[1 dropb)")
+        );
+    }
+
+    #[test]
+    fn not_hive() {
+        assert_eq!("'yinsh' is not a game of hive", error("(;SU[yinsh])"));
+    }
+
+    #[test]
+    fn unknown_action() {
+        let s = GAME.replace("pmove B bA1", "teleport B bA1");
+        assert_eq!("'teleport B bA1 O 14 bQ-' is not a known action", error(&s));
+    }
+
+    #[test]
+    fn missing_reference() {
+        let s = GAME.replace("dropb wA1 M 12 -wQ", "dropb wA1 M 12");
+        assert_eq!(
+            "'dropb wA1 M 12' doesn't say where the piece went",
+            error(&s)
+        );
+    }
+
+    #[test]
+    fn illegal_move() {
+        let s = GAME.replace("dropb bA1 N 15 /bQ", "dropb bA1 N 15 bQ/");
+        match parse_sgf(&s) {
+            Err(SgfError::Replay(GameStringError::IllegalMove(m, MoveError::OccupiedHex))) => {
+                assert_eq!("bA1 bQ/", m)
+            }
+            _ => panic!("expected an illegal move"),
+        }
+    }
+}
@@ -1,9 +1,79 @@
+use axial::Axial;
+use board::Board;
+use tile::slides;
 use tile::Tile;
+use tile::TileKind;
 
 pub struct Ant {}
 
 impl Tile for Ant {
-    fn render(&self) -> char {
-        'A'
+    fn kind(&self) -> TileKind {
+        TileKind::Ant
+    }
+
+    fn possible_moves(&self, board: &Board, position: Axial) -> Vec<Axial> {
+        let mut visited = vec![position];
+        let mut to_visit = vec![position];
+        while let Some(from) = to_visit.pop() {
+            for to in slides(board, from) {
+                if !visited.contains(&to) {
+                    visited.push(to);
+                    to_visit.push(to);
+                }
+            }
+        }
+
+        visited.retain(|visited_position| *visited_position != position);
+        visited.sort();
+        visited
+    }
+}
+
+#[cfg(test)]
+mod possible_moves {
+    use super::*;
+    use board::helpers::board_with;
+    use tile::{ant, queen};
+
+    #[test]
+    fn around_single_tile() {
+        let b = board_with(vec![
+            (ant(), Axial::zero()),
+            (queen(), Axial::zero().south()),
+        ]);
+        let moves = b.possible_moves(Axial::zero());
+
+        assert_eq!(5, moves.len());
+        assert_eq!(false, moves.contains(&Axial::zero()));
+    }
+
+    #[test]
+    fn around_line_of_tiles() {
+        let b = board_with(vec![
+            (ant(), Axial::zero()),
+            (queen(), Axial::zero().south()),
+            (queen(), Axial::zero().south().south()),
+        ]);
+        let moves = b.possible_moves(Axial::zero());
+
+        assert_eq!(7, moves.len());
+        assert_eq!(true, moves.contains(&Axial::zero().south().south().south()));
+    }
+
+    #[test]
+    fn cannot_enter_surrounded_gap() {
+        // The centre of this ring is only reachable through a gate
+        let centre = Axial::new(5, 5);
+        let mut tiles = vec![(ant(), centre.north().north())];
+        for position in centre.surrounding_positions() {
+            if position != centre.north_east() {
+                tiles.push((queen(), position));
+            }
+        }
+        let b = board_with(tiles);
+        let moves = b.possible_moves(centre.north().north());
+
+        assert_eq!(false, moves.contains(&centre));
+        assert_eq!(true, moves.contains(&centre.north_east()));
     }
 }
@@ -1,9 +1,86 @@
+use axial::Axial;
+use board::Board;
+use tile::climbs;
 use tile::Tile;
+use tile::TileKind;
 
 pub struct Beetle {}
 
 impl Tile for Beetle {
-    fn render(&self) -> char {
-        'B'
+    fn kind(&self) -> TileKind {
+        TileKind::Beetle
+    }
+
+    fn possible_moves(&self, board: &Board, position: Axial) -> Vec<Axial> {
+        climbs(board, position)
+    }
+}
+
+#[cfg(test)]
+mod possible_moves {
+    use super::*;
+    use board::helpers::board_with;
+    use tile::{ant, beetle};
+
+    #[test]
+    fn next_to_single_tile() {
+        let b = board_with(vec![
+            (beetle(), Axial::zero()),
+            (ant(), Axial::zero().south()),
+        ]);
+        let moves = b.possible_moves(Axial::zero());
+
+        assert_eq!(3, moves.len());
+        assert_eq!(true, moves.contains(&Axial::zero().south()));
+        assert_eq!(true, moves.contains(&Axial::zero().south_west()));
+        assert_eq!(true, moves.contains(&Axial::zero().south_east()));
+    }
+
+    #[test]
+    fn across_top_of_hive() {
+        let b = board_with(vec![
+            (ant(), Axial::zero()),
+            (ant(), Axial::zero().south()),
+            (beetle(), Axial::zero()),
+        ]);
+        let moves = b.possible_moves(Axial::zero());
+
+        assert_eq!(6, moves.len());
+        assert_eq!(true, moves.contains(&Axial::zero().north()));
+    }
+
+    #[test]
+    fn climbs_into_surrounded_gap() {
+        let mut tiles = vec![(beetle(), Axial::zero().north().north())];
+        for position in Axial::zero().surrounding_positions() {
+            if position != Axial::zero().north_east() {
+                tiles.push((ant(), position));
+            }
+        }
+        let b = board_with(tiles);
+        let moves = b.possible_moves(Axial::zero().north().north());
+
+        // Sliding into the gap is blocked but climbing over it is not
+        assert_eq!(false, moves.contains(&Axial::zero()));
+        assert_eq!(true, moves.contains(&Axial::zero().north()));
+    }
+
+    #[test]
+    fn gate_blocks_between_stacks() {
+        let b = board_with(vec![
+            (ant(), Axial::zero()),
+            (ant(), Axial::zero().north_west()),
+            (beetle(), Axial::zero().north_west()),
+            (ant(), Axial::zero().north_east()),
+            (beetle(), Axial::zero().north_east()),
+            (ant(), Axial::zero().north()),
+            (beetle(), Axial::zero()),
+        ]);
+
+        // Both sides of the edge to the north are two tiles high while the
+        // beetle is climbing from and to a single tile
+        let moves = b.possible_moves(Axial::zero());
+        assert_eq!(false, moves.contains(&Axial::zero().north()));
+        assert_eq!(true, moves.contains(&Axial::zero().south()));
     }
 }
@@ -1,9 +1,81 @@
+use axial::Axial;
+use board::Board;
 use tile::Tile;
+use tile::TileKind;
 
 pub struct GrassHopper {}
 
 impl Tile for GrassHopper {
-    fn render(&self) -> char {
-        'G'
+    fn kind(&self) -> TileKind {
+        TileKind::GrassHopper
+    }
+
+    fn possible_moves(&self, board: &Board, position: Axial) -> Vec<Axial> {
+        let directions: Vec<fn(&Axial) -> Axial> = vec![
+            Axial::north,
+            Axial::north_east,
+            Axial::south_east,
+            Axial::south,
+            Axial::south_west,
+            Axial::north_west,
+        ];
+
+        directions
+            .into_iter()
+            .filter(|direction| board.is_occupied(&direction(&position)))
+            .map(|direction| {
+                let mut landing = direction(&position);
+                while board.is_occupied(&landing) {
+                    landing = direction(&landing);
+                }
+                landing
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod possible_moves {
+    use super::*;
+    use board::helpers::board_with;
+    use tile::{ant, grass_hopper};
+
+    #[test]
+    fn over_single_tile() {
+        let b = board_with(vec![
+            (grass_hopper(), Axial::zero()),
+            (ant(), Axial::zero().south()),
+        ]);
+        let moves = b.possible_moves(Axial::zero());
+
+        assert_eq!(1, moves.len());
+        assert_eq!(Axial::zero().south().south(), moves[0]);
+    }
+
+    #[test]
+    fn over_line_of_tiles() {
+        let b = board_with(vec![
+            (grass_hopper(), Axial::zero()),
+            (ant(), Axial::zero().north_west()),
+            (ant(), Axial::zero().north_west().north_west()),
+            (ant(), Axial::zero().north_west().north_west().north_west()),
+        ]);
+        let moves = b.possible_moves(Axial::new(0, 0));
+
+        assert_eq!(1, moves.len());
+        assert_eq!(Axial::new(-4, 0), moves[0]);
+    }
+
+    #[test]
+    fn ignores_gates() {
+        let mut tiles = vec![(grass_hopper(), Axial::zero())];
+        for position in Axial::zero().surrounding_positions() {
+            tiles.push((ant(), position));
+        }
+        let b = board_with(tiles);
+        let moves = b.possible_moves(Axial::zero());
+
+        assert_eq!(6, moves.len());
+        assert_eq!(true, moves.contains(&Axial::zero().north().north()));
     }
 }
@@ -0,0 +1,104 @@
+use axial::Axial;
+use board::Board;
+use tile::climbs;
+use tile::Tile;
+use tile::TileKind;
+
+pub struct Ladybug {}
+
+impl Tile for Ladybug {
+    fn kind(&self) -> TileKind {
+        TileKind::Ladybug
+    }
+
+    // Two steps across the top of the hive and then one back down
+    fn possible_moves(&self, board: &Board, position: Axial) -> Vec<Axial> {
+        let mut moves = Vec::new();
+        for first in climbs(board, position) {
+            if !board.is_occupied(&first) {
+                continue;
+            }
+            for second in climbs(board, first) {
+                if !board.is_occupied(&second) || second == position {
+                    continue;
+                }
+                for third in climbs(board, second) {
+                    if !board.is_occupied(&third) && third != position {
+                        moves.push(third);
+                    }
+                }
+            }
+        }
+        moves.sort();
+        moves.dedup();
+        moves
+    }
+}
+
+#[cfg(test)]
+mod possible_moves {
+    use super::*;
+    use board::helpers::board_with;
+    use tile::{ant, beetle, ladybug};
+
+    #[test]
+    fn next_to_single_tile() {
+        let b = board_with(vec![
+            (ladybug(), Axial::zero()),
+            (ant(), Axial::zero().south()),
+        ]);
+
+        // There's no second tile to walk across
+        assert_eq!(0, b.possible_moves(Axial::zero()).len());
+    }
+
+    #[test]
+    fn over_two_tiles() {
+        let b = board_with(vec![
+            (ladybug(), Axial::zero()),
+            (ant(), Axial::zero().south()),
+            (ant(), Axial::zero().south().south()),
+        ]);
+        let moves = b.possible_moves(Axial::zero());
+
+        assert_eq!(5, moves.len());
+        assert_eq!(false, moves.contains(&Axial::zero().south_east()));
+        assert_eq!(true, moves.contains(&Axial::zero().south().south_east()));
+        assert_eq!(true, moves.contains(&Axial::zero().south().south().south()));
+    }
+
+    #[test]
+    fn into_surrounded_gap() {
+        let mut tiles = vec![(ladybug(), Axial::zero().north().north())];
+        for position in Axial::zero().surrounding_positions() {
+            tiles.push((ant(), position));
+        }
+        tiles.retain(|(_, position)| *position != Axial::zero().north_east());
+        let b = board_with(tiles);
+
+        assert_eq!(
+            true,
+            b.possible_moves(Axial::zero().north().north())
+                .contains(&Axial::zero())
+        );
+    }
+
+    #[test]
+    fn gate_blocks_climbing_up() {
+        let b = board_with(vec![
+            (ladybug(), Axial::zero()),
+            (ant(), Axial::zero().north()),
+            (ant(), Axial::zero().north_west()),
+            (beetle(), Axial::zero().north_west()),
+            (ant(), Axial::zero().north_east()),
+            (beetle(), Axial::zero().north_east()),
+        ]);
+        let moves = b.possible_moves(Axial::zero());
+
+        // Climbing straight onto the north tile means squeezing between two
+        // taller stacks, so the ladybug can only get there by going round
+        assert_eq!(3, moves.len());
+        assert_eq!(false, moves.contains(&Axial::zero().south_west()));
+        assert_eq!(true, moves.contains(&Axial::zero().north().north()));
+    }
+}
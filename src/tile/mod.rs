@@ -1,13 +1,110 @@
 mod ant;
 mod beetle;
 mod grass_hopper;
+mod ladybug;
+mod mosquito;
+mod pillbug;
 mod queen;
 mod spider;
 
+use axial::Axial;
+use board::Board;
+use std::cmp;
 use std::rc::Rc;
 
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash, PartialOrd, Ord)]
+pub enum TileKind {
+    Queen,
+    Beetle,
+    Spider,
+    Ant,
+    GrassHopper,
+    Mosquito,
+    Ladybug,
+    Pillbug,
+}
+
+impl TileKind {
+    pub fn letter(&self) -> char {
+        match self {
+            TileKind::Queen => 'Q',
+            TileKind::Beetle => 'B',
+            TileKind::Spider => 'S',
+            TileKind::Ant => 'A',
+            TileKind::GrassHopper => 'G',
+            TileKind::Mosquito => 'M',
+            TileKind::Ladybug => 'L',
+            TileKind::Pillbug => 'P',
+        }
+    }
+
+    pub fn from_letter(letter: char) -> Option<TileKind> {
+        match letter {
+            'Q' => Some(TileKind::Queen),
+            'B' => Some(TileKind::Beetle),
+            'S' => Some(TileKind::Spider),
+            'A' => Some(TileKind::Ant),
+            'G' => Some(TileKind::GrassHopper),
+            'M' => Some(TileKind::Mosquito),
+            'L' => Some(TileKind::Ladybug),
+            'P' => Some(TileKind::Pillbug),
+            _ => None,
+        }
+    }
+
+    // Each player only ever has one of these so they go unnumbered
+    pub fn is_unique(&self) -> bool {
+        matches!(
+            self,
+            TileKind::Queen | TileKind::Mosquito | TileKind::Ladybug | TileKind::Pillbug
+        )
+    }
+}
+
 pub trait Tile {
-    fn render(&self) -> char;
+    fn kind(&self) -> TileKind;
+
+    fn render(&self) -> char {
+        self.kind().letter()
+    }
+
+    // `board` is the current position with this tile already lifted off `position`
+    fn possible_moves(&self, board: &Board, position: Axial) -> Vec<Axial> {
+        Vec::new()
+    }
+
+    // Pairs of positions that this tile can lift a neighbouring tile from and
+    // to without moving itself
+    fn possible_throws(&self, board: &Board, position: Axial) -> Vec<(Axial, Axial)> {
+        Vec::new()
+    }
+}
+
+// Moving on or off the hive is blocked when the positions either side of the
+// edge being crossed are both stacked higher than the tile's start and end
+fn can_climb(board: &Board, from: Axial, to: Axial) -> bool {
+    let height = cmp::max(board.height(&from), board.height(&to));
+    if height == 0 {
+        return board.can_slide(from, to);
+    }
+
+    from.shared_neighbours(&to)
+        .iter()
+        .any(|position| board.height(position) <= height)
+}
+
+fn climbs(board: &Board, from: Axial) -> Vec<Axial> {
+    from.surrounding_positions()
+        .into_iter()
+        .filter(|to| can_climb(board, from, *to))
+        .collect()
+}
+
+fn slides(board: &Board, from: Axial) -> Vec<Axial> {
+    from.surrounding_positions()
+        .into_iter()
+        .filter(|to| board.can_slide(from, *to))
+        .collect()
 }
 
 pub fn ant() -> Rc<Tile> {
@@ -30,6 +127,31 @@ pub fn spider() -> Rc<Tile> {
     Rc::new(spider::Spider {})
 }
 
+pub fn mosquito() -> Rc<Tile> {
+    Rc::new(mosquito::Mosquito {})
+}
+
+pub fn ladybug() -> Rc<Tile> {
+    Rc::new(ladybug::Ladybug {})
+}
+
+pub fn pillbug() -> Rc<Tile> {
+    Rc::new(pillbug::Pillbug {})
+}
+
+pub fn from_kind(kind: TileKind) -> Rc<Tile> {
+    match kind {
+        TileKind::Queen => queen(),
+        TileKind::Beetle => beetle(),
+        TileKind::Spider => spider(),
+        TileKind::Ant => ant(),
+        TileKind::GrassHopper => grass_hopper(),
+        TileKind::Mosquito => mosquito(),
+        TileKind::Ladybug => ladybug(),
+        TileKind::Pillbug => pillbug(),
+    }
+}
+
 pub fn standard_game_tiles() -> Vec<Rc<Tile>> {
     vec![
         queen(),
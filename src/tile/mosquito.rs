@@ -0,0 +1,138 @@
+use axial::Axial;
+use board::Board;
+use tile::climbs;
+use tile::pillbug;
+use tile::Tile;
+use tile::TileKind;
+
+pub struct Mosquito {}
+
+impl Tile for Mosquito {
+    fn kind(&self) -> TileKind {
+        TileKind::Mosquito
+    }
+
+    fn possible_moves(&self, board: &Board, position: Axial) -> Vec<Axial> {
+        // On top of the hive the mosquito can only move like a beetle
+        if board.is_occupied(&position) {
+            return climbs(board, position);
+        }
+
+        let mut moves = Vec::new();
+        for surrounding_position in position.surrounding_positions() {
+            if let Some(placed_tile) = board.top_tile(&surrounding_position) {
+                if placed_tile.tile.kind() != TileKind::Mosquito {
+                    moves.extend(placed_tile.tile.possible_moves(board, position));
+                }
+            }
+        }
+        moves.sort();
+        moves.dedup();
+        moves
+    }
+
+    fn possible_throws(&self, board: &Board, position: Axial) -> Vec<(Axial, Axial)> {
+        let next_to_pillbug = position
+            .surrounding_positions()
+            .iter()
+            .any(|surrounding_position| {
+                board
+                    .top_tile(surrounding_position)
+                    .is_some_and(|placed_tile| placed_tile.tile.kind() == TileKind::Pillbug)
+            });
+
+        if next_to_pillbug {
+            pillbug::throws(board, position)
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod possible_moves {
+    use super::*;
+    use board::helpers::board_with;
+    use tile::{ant, beetle, grass_hopper, mosquito, pillbug, queen};
+
+    #[test]
+    fn next_to_grass_hopper() {
+        let b = board_with(vec![
+            (mosquito(), Axial::zero()),
+            (grass_hopper(), Axial::zero().south()),
+        ]);
+        assert_eq!(
+            vec![Axial::zero().south().south()],
+            b.possible_moves(Axial::zero())
+        );
+    }
+
+    #[test]
+    fn next_to_queen_and_grass_hopper() {
+        let b = board_with(vec![
+            (mosquito(), Axial::zero()),
+            (grass_hopper(), Axial::zero().south()),
+            (queen(), Axial::zero().south_east()),
+        ]);
+        let moves = b.possible_moves(Axial::zero());
+
+        assert_eq!(4, moves.len());
+        assert_eq!(true, moves.contains(&Axial::zero().south().south()));
+        assert_eq!(true, moves.contains(&Axial::new(2, 0)));
+        assert_eq!(true, moves.contains(&Axial::zero().north_east()));
+        assert_eq!(true, moves.contains(&Axial::zero().south_west()));
+    }
+
+    #[test]
+    fn next_to_only_mosquitoes() {
+        let b = board_with(vec![
+            (mosquito(), Axial::zero()),
+            (mosquito(), Axial::zero().south()),
+        ]);
+        assert_eq!(0, b.possible_moves(Axial::zero()).len());
+    }
+
+    #[test]
+    fn next_to_stacked_beetle() {
+        let b = board_with(vec![
+            (mosquito(), Axial::zero()),
+            (ant(), Axial::zero().south()),
+            (beetle(), Axial::zero().south()),
+        ]);
+        let moves = b.possible_moves(Axial::zero());
+
+        assert_eq!(3, moves.len());
+        assert_eq!(true, moves.contains(&Axial::zero().south()));
+    }
+
+    #[test]
+    fn on_top_of_hive() {
+        let b = board_with(vec![
+            (grass_hopper(), Axial::zero()),
+            (ant(), Axial::zero().south()),
+            (mosquito(), Axial::zero()),
+        ]);
+        let moves = b.possible_moves(Axial::zero());
+
+        assert_eq!(6, moves.len());
+        assert_eq!(true, moves.contains(&Axial::zero().north()));
+    }
+
+    #[test]
+    fn throws_next_to_pillbug() {
+        let b = board_with(vec![
+            (mosquito(), Axial::zero()),
+            (pillbug(), Axial::zero().south()),
+        ]);
+        assert_eq!(5, b.possible_throws(Axial::zero()).len());
+    }
+
+    #[test]
+    fn no_throws_away_from_pillbug() {
+        let b = board_with(vec![
+            (mosquito(), Axial::zero()),
+            (ant(), Axial::zero().south()),
+        ]);
+        assert_eq!(0, b.possible_throws(Axial::zero()).len());
+    }
+}
@@ -0,0 +1,112 @@
+use axial::Axial;
+use board::Board;
+use tile::can_climb;
+use tile::slides;
+use tile::Tile;
+use tile::TileKind;
+
+pub struct Pillbug {}
+
+// Lifting a tile up over the pillbug and back down the other side has to get
+// through the same gates as a beetle would
+pub fn throws(board: &Board, position: Axial) -> Vec<(Axial, Axial)> {
+    if board.height(&position) != 1 {
+        return Vec::new();
+    }
+
+    let mut throws = Vec::new();
+    for from in position.surrounding_positions() {
+        if board.height(&from) != 1 {
+            continue;
+        }
+
+        let lifted = board.without_tile_at(from);
+        if !can_climb(&lifted, from, position) {
+            continue;
+        }
+        for to in position.surrounding_positions() {
+            if to != from && !lifted.is_occupied(&to) && can_climb(&lifted, position, to) {
+                throws.push((from, to));
+            }
+        }
+    }
+    throws
+}
+
+impl Tile for Pillbug {
+    fn kind(&self) -> TileKind {
+        TileKind::Pillbug
+    }
+
+    fn possible_moves(&self, board: &Board, position: Axial) -> Vec<Axial> {
+        slides(board, position)
+    }
+
+    fn possible_throws(&self, board: &Board, position: Axial) -> Vec<(Axial, Axial)> {
+        throws(board, position)
+    }
+}
+
+#[cfg(test)]
+mod possible_throws {
+    use super::*;
+    use board::helpers::board_with;
+    use tile::{ant, beetle, pillbug};
+
+    #[test]
+    fn single_tile() {
+        let b = board_with(vec![
+            (pillbug(), Axial::zero()),
+            (ant(), Axial::zero().south()),
+        ]);
+        let throws = b.possible_throws(Axial::zero());
+
+        assert_eq!(5, throws.len());
+        assert_eq!(
+            true,
+            throws.contains(&(Axial::zero().south(), Axial::zero().north()))
+        );
+    }
+
+    #[test]
+    fn not_pinned_tiles() {
+        let b = board_with(vec![
+            (pillbug(), Axial::zero()),
+            (ant(), Axial::zero().south()),
+            (ant(), Axial::zero().south().south()),
+        ]);
+        assert_eq!(0, b.possible_throws(Axial::zero()).len());
+    }
+
+    #[test]
+    fn not_stacked_tiles() {
+        let b = board_with(vec![
+            (pillbug(), Axial::zero()),
+            (ant(), Axial::zero().south()),
+            (beetle(), Axial::zero().south()),
+        ]);
+        assert_eq!(0, b.possible_throws(Axial::zero()).len());
+    }
+
+    #[test]
+    fn not_through_gates() {
+        let b = board_with(vec![
+            (pillbug(), Axial::zero()),
+            (ant(), Axial::zero().south()),
+            (ant(), Axial::zero().north_west()),
+            (beetle(), Axial::zero().north_west()),
+            (ant(), Axial::zero().north_east()),
+            (beetle(), Axial::zero().north_east()),
+        ]);
+        let throws = b.possible_throws(Axial::zero());
+
+        assert_eq!(
+            false,
+            throws.contains(&(Axial::zero().south(), Axial::zero().north()))
+        );
+        assert_eq!(
+            true,
+            throws.contains(&(Axial::zero().south(), Axial::zero().south_west()))
+        );
+    }
+}
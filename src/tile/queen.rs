@@ -1,9 +1,64 @@
+use axial::Axial;
+use board::Board;
+use tile::slides;
 use tile::Tile;
+use tile::TileKind;
 
 pub struct Queen {}
 
 impl Tile for Queen {
-    fn render(&self) -> char {
-        'Q'
+    fn kind(&self) -> TileKind {
+        TileKind::Queen
+    }
+
+    fn possible_moves(&self, board: &Board, position: Axial) -> Vec<Axial> {
+        slides(board, position)
+    }
+}
+
+#[cfg(test)]
+mod possible_moves {
+    use super::*;
+    use board::helpers::board_with;
+    use tile::{ant, queen};
+
+    #[test]
+    fn next_to_single_tile() {
+        let b = board_with(vec![
+            (queen(), Axial::zero()),
+            (ant(), Axial::zero().south()),
+        ]);
+        let moves = b.possible_moves(Axial::zero());
+
+        assert_eq!(2, moves.len());
+        assert_eq!(true, moves.contains(&Axial::zero().south_west()));
+        assert_eq!(true, moves.contains(&Axial::zero().south_east()));
+    }
+
+    #[test]
+    fn along_hive_edge() {
+        let b = board_with(vec![
+            (queen(), Axial::zero()),
+            (ant(), Axial::zero().south()),
+            (ant(), Axial::zero().south_east()),
+        ]);
+        let moves = b.possible_moves(Axial::zero());
+
+        assert_eq!(2, moves.len());
+        assert_eq!(true, moves.contains(&Axial::zero().south_west()));
+        assert_eq!(true, moves.contains(&Axial::zero().north_east()));
+    }
+
+    #[test]
+    fn gate_blocks_slide() {
+        let b = board_with(vec![
+            (queen(), Axial::zero()),
+            (ant(), Axial::zero().north()),
+            (ant(), Axial::zero().south_east()),
+            (ant(), Axial::zero().south()),
+            (ant(), Axial::zero().south_west()),
+            (ant(), Axial::zero().north_west()),
+        ]);
+        assert_eq!(0, b.possible_moves(Axial::zero()).len());
     }
 }
@@ -1,9 +1,92 @@
+use axial::Axial;
+use board::Board;
+use tile::slides;
 use tile::Tile;
+use tile::TileKind;
 
 pub struct Spider {}
 
+static STEPS: usize = 3;
+
+fn walk(board: &Board, path: &mut Vec<Axial>, destinations: &mut Vec<Axial>) {
+    let from = *path.last().unwrap();
+    if path.len() == STEPS + 1 {
+        destinations.push(from);
+        return;
+    }
+
+    for to in slides(board, from) {
+        if !path.contains(&to) {
+            path.push(to);
+            walk(board, path, destinations);
+            path.pop();
+        }
+    }
+}
+
 impl Tile for Spider {
-    fn render(&self) -> char {
-        'S'
+    fn kind(&self) -> TileKind {
+        TileKind::Spider
+    }
+
+    fn possible_moves(&self, board: &Board, position: Axial) -> Vec<Axial> {
+        let mut destinations = Vec::new();
+        walk(board, &mut vec![position], &mut destinations);
+        destinations.sort();
+        destinations.dedup();
+        destinations
+    }
+}
+
+#[cfg(test)]
+mod possible_moves {
+    use super::*;
+    use board::helpers::board_with;
+    use tile::{queen, spider};
+
+    #[test]
+    fn around_single_tile() {
+        let b = board_with(vec![
+            (spider(), Axial::zero()),
+            (queen(), Axial::zero().south()),
+        ]);
+        let moves = b.possible_moves(Axial::zero());
+
+        // Both directions around the tile end on the same position
+        assert_eq!(1, moves.len());
+        assert_eq!(Axial::zero().south().south(), moves[0]);
+    }
+
+    #[test]
+    fn around_line_of_tiles() {
+        let b = board_with(vec![
+            (spider(), Axial::zero()),
+            (queen(), Axial::zero().south()),
+            (queen(), Axial::zero().south().south()),
+        ]);
+        let moves = b.possible_moves(Axial::zero());
+
+        assert_eq!(2, moves.len());
+        assert_eq!(
+            true,
+            moves.contains(&Axial::zero().south().south().south_west())
+        );
+        assert_eq!(
+            true,
+            moves.contains(&Axial::zero().south().south().south_east())
+        );
+    }
+
+    #[test]
+    fn never_ends_on_start() {
+        let b = board_with(vec![
+            (spider(), Axial::zero()),
+            (queen(), Axial::zero().south()),
+            (queen(), Axial::zero().south_east()),
+        ]);
+        assert_eq!(
+            false,
+            b.possible_moves(Axial::zero()).contains(&Axial::zero())
+        );
     }
 }
@@ -0,0 +1,351 @@
+use game::{Game, GameState};
+use game_string::{game_string, parse_game_string, parse_game_type};
+use notation::{format_move, parse_move};
+use rule_set::RuleSet;
+use std::io;
+use std::io::{BufRead, Write};
+
+// Drives a game over the Universal Hive Protocol, each command's response is
+// followed by a line containing `ok`. A player with nothing to do has to
+// `pass` themselves, as the protocol expects
+pub struct Engine {
+    game: Option<Game>,
+}
+
+impl Engine {
+    pub fn new() -> Engine {
+        Engine { game: None }
+    }
+
+    pub fn run<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<()> {
+        writeln!(output, "{}", self.info())?;
+        writeln!(output, "ok")?;
+        output.flush()?;
+
+        for line in input.lines() {
+            let line = line?;
+            let command = line.trim();
+            if command.is_empty() {
+                continue;
+            }
+            if command == "exit" {
+                break;
+            }
+
+            let response = self.respond(command);
+            if !response.is_empty() {
+                writeln!(output, "{}", response)?;
+            }
+            writeln!(output, "ok")?;
+            output.flush()?;
+        }
+        Ok(())
+    }
+
+    pub fn respond(&mut self, command: &str) -> String {
+        let mut words = command.splitn(2, ' ');
+        let name = words.next().unwrap_or("");
+        let arguments = words.next().unwrap_or("").trim();
+        match name {
+            "info" => self.info(),
+            "newgame" => self.new_game(arguments),
+            "play" => self.play(arguments),
+            "pass" => self.play("pass"),
+            "validmoves" => self.valid_moves(),
+            "bestmove" => self.best_move(),
+            "undo" => self.undo(arguments),
+            "options" => self.options(arguments),
+            _ => format!("err unknown command '{}'", name),
+        }
+    }
+
+    fn info(&self) -> String {
+        format!(
+            "id {} v{}\nMosquito;Ladybug;Pillbug",
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION")
+        )
+    }
+
+    // Takes either a game type or a full game string to carry on from
+    fn new_game(&mut self, arguments: &str) -> String {
+        let mut game = if arguments.is_empty() {
+            Game::new(RuleSet::default())
+        } else if arguments.contains(';') {
+            match parse_game_string(arguments) {
+                Ok(game) => game,
+                Err(error) => return format!("err {}", error),
+            }
+        } else {
+            match parse_game_type(arguments) {
+                Some(rules) => Game::new(rules),
+                None => return format!("err '{}' is not a valid game type", arguments),
+            }
+        };
+        game.set_auto_pass(false);
+        let response = game_string(&game);
+        self.game = Some(game);
+        response
+    }
+
+    fn play(&mut self, arguments: &str) -> String {
+        let game = match self.game {
+            Some(ref mut game) => game,
+            None => return no_game(),
+        };
+        let m = match parse_move(game.board(), arguments) {
+            Ok(m) => m,
+            Err(error) => return format!("invalidmove {}", error),
+        };
+        match game.play(m) {
+            Ok(()) => game_string(game),
+            Err(error) => format!("invalidmove {}", error),
+        }
+    }
+
+    fn valid_moves(&self) -> String {
+        let game = match self.game {
+            Some(ref game) if game.state() != GameState::InProgress => return game_over(),
+            Some(ref game) => game,
+            None => return no_game(),
+        };
+        game.legal_moves()
+            .iter()
+            .map(|m| format_move(game.board(), m))
+            .collect::<Vec<_>>()
+            .join(";")
+    }
+
+    // There's no search yet so the time or depth limit is ignored
+    fn best_move(&self) -> String {
+        let game = match self.game {
+            Some(ref game) if game.state() != GameState::InProgress => return game_over(),
+            Some(ref game) => game,
+            None => return no_game(),
+        };
+        let m = game
+            .player(game.board().current_player())
+            .get_move(game.board());
+        format_move(game.board(), &m)
+    }
+
+    fn undo(&mut self, arguments: &str) -> String {
+        let game = match self.game {
+            Some(ref mut game) => game,
+            None => return no_game(),
+        };
+        let count = if arguments.is_empty() {
+            1
+        } else {
+            match arguments.parse::<usize>() {
+                Ok(count) => count,
+                Err(_) => return format!("err '{}' is not a number of moves", arguments),
+            }
+        };
+        if count > game.board().history().len() {
+            return format!("err cannot undo {} moves", count);
+        }
+        for _ in 0..count {
+            game.undo();
+        }
+        game_string(game)
+    }
+
+    // There are no options to get or set
+    fn options(&self, arguments: &str) -> String {
+        let mut words = arguments.split_whitespace();
+        match (words.next(), words.next()) {
+            (None, _) => String::new(),
+            (Some("get"), Some(name)) | (Some("set"), Some(name)) => {
+                format!("err unknown option '{}'", name)
+            }
+            _ => format!("err '{}' is not a valid options command", arguments),
+        }
+    }
+}
+
+fn no_game() -> String {
+    "err no game in progress".to_string()
+}
+
+fn game_over() -> String {
+    "err the game is over".to_string()
+}
+
+#[cfg(test)]
+mod respond {
+    use super::*;
+    use axial::Axial;
+    use game::helpers::game_with_auto_pass;
+    use player::PlayerNumber;
+    use tile::{ant, queen};
+
+    fn engine_after(commands: Vec<&str>) -> Engine {
+        let mut engine = Engine::new();
+        for command in commands {
+            engine.respond(command);
+        }
+        engine
+    }
+
+    #[test]
+    fn info() {
+        let response = Engine::new().respond("info");
+        assert_eq!(true, response.starts_with("id rust-hive v"));
+    }
+
+    #[test]
+    fn new_game() {
+        let mut engine = Engine::new();
+        assert_eq!("Base;NotStarted;White[1]", engine.respond("newgame"));
+        assert_eq!(
+            "Base+ML;NotStarted;White[1]",
+            engine.respond("newgame Base+ML")
+        );
+        assert_eq!(
+            "err 'Extended' is not a valid game type",
+            engine.respond("newgame Extended")
+        );
+    }
+
+    #[test]
+    fn new_game_from_game_string() {
+        let mut engine = Engine::new();
+        assert_eq!(
+            "Base;InProgress;White[2];wQ;bA1 /wQ",
+            engine.respond("newgame Base;InProgress;White[2];wQ;bA1 /wQ")
+        );
+        assert_eq!(
+            "err 'bA1 wQ' is illegal, that position is already occupied",
+            engine.respond("newgame Base;InProgress;White[2];wQ;bA1 wQ")
+        );
+    }
+
+    #[test]
+    fn play() {
+        let mut engine = engine_after(vec!["newgame", "play wQ"]);
+        assert_eq!(
+            "Base;InProgress;White[2];wQ;bA1 /wQ",
+            engine.respond("play bA1 /wQ")
+        );
+    }
+
+    #[test]
+    fn invalid_move() {
+        let mut engine = engine_after(vec!["newgame", "play wQ"]);
+        assert_eq!(
+            "invalidmove that position is already occupied",
+            engine.respond("play bA1 wQ")
+        );
+        assert_eq!(
+            "invalidmove 'wQ -' is not a valid move",
+            engine.respond("play wQ -")
+        );
+    }
+
+    #[test]
+    fn pass_with_moves_available() {
+        let mut engine = engine_after(vec!["newgame"]);
+        assert_eq!(
+            "invalidmove passing is only allowed with no other moves",
+            engine.respond("pass")
+        );
+    }
+
+    #[test]
+    fn only_pass() {
+        let mut engine = Engine {
+            game: Some(game_with_auto_pass(
+                vec![
+                    (ant(), Axial::zero(), PlayerNumber::Two),
+                    (queen(), Axial::zero().south(), PlayerNumber::One),
+                    (ant(), Axial::zero().south().south(), PlayerNumber::Two),
+                    (queen(), Axial::new(1, 1), PlayerNumber::Two),
+                ],
+                false,
+            )),
+        };
+        assert_eq!("pass", engine.respond("validmoves"));
+        assert_eq!("pass", engine.respond("bestmove"));
+        assert_eq!("Base;InProgress;Black[1];pass", engine.respond("pass"));
+        assert_eq!("Base;NotStarted;White[1]", engine.respond("undo"));
+    }
+
+    #[test]
+    fn valid_moves() {
+        let mut engine = engine_after(vec!["newgame", "play wQ"]);
+        let response = engine.respond("validmoves");
+        let moves: Vec<&str> = response.split(';').collect();
+        assert_eq!(30, moves.len());
+        assert_eq!(true, moves.contains(&"bG1 wQ/"));
+    }
+
+    #[test]
+    fn best_move_is_valid() {
+        let mut engine = engine_after(vec!["newgame", "play wQ"]);
+        let best_move = engine.respond("bestmove time 00:00:01");
+        assert_eq!(
+            true,
+            engine
+                .respond("validmoves")
+                .split(';')
+                .any(|m| m == best_move)
+        );
+    }
+
+    #[test]
+    fn undo() {
+        let mut engine = engine_after(vec!["newgame", "play wQ", "play bQ /wQ"]);
+        assert_eq!("Base;NotStarted;White[1]", engine.respond("undo 2"));
+        assert_eq!("err cannot undo 1 moves", engine.respond("undo"));
+    }
+
+    #[test]
+    fn no_game() {
+        let mut engine = Engine::new();
+        for command in vec!["play wQ", "pass", "validmoves", "bestmove", "undo"] {
+            assert_eq!("err no game in progress", engine.respond(command));
+        }
+    }
+
+    #[test]
+    fn options() {
+        let mut engine = Engine::new();
+        assert_eq!("", engine.respond("options"));
+        assert_eq!(
+            "err unknown option 'Depth'",
+            engine.respond("options get Depth")
+        );
+    }
+
+    #[test]
+    fn unknown_command() {
+        assert_eq!("err unknown command 'jump'", Engine::new().respond("jump"));
+    }
+}
+
+#[cfg(test)]
+mod run {
+    use super::*;
+
+    #[test]
+    fn script() {
+        let script = "newgame\nplay wQ\n\nbestmove\nexit\ninfo\n";
+        let mut output = Vec::new();
+        Engine::new().run(script.as_bytes(), &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            vec![
+                "Base;NotStarted;White[1]",
+                "ok",
+                "Base;InProgress;Black[1];wQ",
+                "ok",
+            ],
+            lines[3..7].to_vec()
+        );
+        assert_eq!(9, lines.len());
+        assert_eq!("ok", lines[8]);
+    }
+}